@@ -0,0 +1,60 @@
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use token_cell::{ghost::GhostToken, prelude::*, RuntimeToken};
+
+token_cell::unsafe_token!(Unchecked);
+token_cell::singleton_token!(Singleton);
+
+fn try_borrow_mut(c: &mut Criterion) {
+    let mut group = c.benchmark_group("try_borrow_mut");
+    let mut token = Unchecked::new().unwrap();
+    let cell = TokenCell::new(0u64, &token);
+    group.bench_function("unsafe", |b| {
+        b.iter(|| *black_box(&cell).try_borrow_mut(&mut token).unwrap() += 1)
+    });
+    let mut token = RuntimeToken::new().unwrap();
+    let cell = TokenCell::new(0u64, &token);
+    group.bench_function("runtime", |b| {
+        b.iter(|| *black_box(&cell).try_borrow_mut(&mut token).unwrap() += 1)
+    });
+    let mut token = Singleton::new().unwrap();
+    let cell = TokenCell::new(0u64, &token);
+    group.bench_function("singleton", |b| {
+        b.iter(|| *black_box(&cell).try_borrow_mut(&mut token).unwrap() += 1)
+    });
+    GhostToken::with_token(|mut token| {
+        let cell = TokenCell::new(0u64, &token);
+        group.bench_function("ghost", |b| {
+            b.iter(|| *black_box(&cell).try_borrow_mut(&mut token).unwrap() += 1)
+        });
+    })
+    .unwrap();
+    group.finish();
+}
+
+fn map_apply(c: &mut Criterion) {
+    let mut group = c.benchmark_group("map_mut_apply");
+    let mut token = Unchecked::new().unwrap();
+    let cell = TokenCell::new(0u64, &token);
+    group.bench_function("unsafe", |b| {
+        b.iter(|| {
+            black_box(&cell)
+                .map_mut(|mut value| *value += 1)
+                .apply(&mut token)
+        })
+    });
+    let mut token = RuntimeToken::new().unwrap();
+    let cell = TokenCell::new(0u64, &token);
+    group.bench_function("runtime", |b| {
+        b.iter(|| {
+            black_box(&cell)
+                .map_mut(|mut value| *value += 1)
+                .try_apply(&mut token)
+                .ok()
+                .unwrap()
+        })
+    });
+    group.finish();
+}
+
+criterion_group!(benches, try_borrow_mut, map_apply);
+criterion_main!(benches);
@@ -0,0 +1,100 @@
+//! A tree whose nodes are shared through `Arc`s and all unlocked by a single token.
+//!
+//! Children are owned by their parent, which they point back to through a `Weak`.
+//! Since `Arc<TokenCell<..>>` dereferences to the cell, nodes are borrowed with the token like any other cell.
+
+use std::sync::{Arc, Weak};
+
+use token_cell::{prelude::*, RuntimeToken};
+
+type Cell<T> = TokenCell<T, RuntimeToken>;
+
+struct Node {
+    value: u32,
+    parent: Weak<Cell<Node>>,
+    children: Vec<Arc<Cell<Node>>>,
+}
+
+fn leaf(value: u32, token: &RuntimeToken) -> Arc<Cell<Node>> {
+    Arc::new(TokenCell::new(
+        Node {
+            value,
+            parent: Weak::new(),
+            children: Vec::new(),
+        },
+        token,
+    ))
+}
+
+fn adopt(parent: &Arc<Cell<Node>>, child: Arc<Cell<Node>>, token: &mut RuntimeToken) {
+    child.borrow_mut(token).parent = Arc::downgrade(parent);
+    parent.borrow_mut(token).children.push(child);
+}
+
+fn sum(node: &Cell<Node>, token: &RuntimeToken) -> u32 {
+    let node = node.borrow(token);
+    node.value
+        + node
+            .children
+            .iter()
+            .map(|child| sum(child, token))
+            .sum::<u32>()
+}
+
+fn depth(node: &Cell<Node>, token: &RuntimeToken) -> usize {
+    match node.borrow(token).parent.upgrade() {
+        Some(parent) => 1 + depth(&parent, token),
+        None => 0,
+    }
+}
+
+/// The node's guard holds the token, so its children are taken out while they are visited.
+fn double(node: &Cell<Node>, token: &mut RuntimeToken) {
+    let children = {
+        let node = node.borrow_mut(token);
+        node.value *= 2;
+        core::mem::take(&mut node.children)
+    };
+    for child in &children {
+        double(child, token);
+    }
+    node.borrow_mut(token).children = children;
+}
+
+/// Moves `child` from its current parent to `parent`.
+fn reparent(child: &Arc<Cell<Node>>, parent: &Arc<Cell<Node>>, token: &mut RuntimeToken) {
+    if let Some(previous) = child.borrow(token).parent.upgrade() {
+        previous
+            .borrow_mut(token)
+            .children
+            .retain(|sibling| !Arc::ptr_eq(sibling, child));
+    }
+    adopt(parent, child.clone(), token);
+}
+
+fn main() {
+    let mut token = RuntimeToken::new().unwrap();
+    let root = leaf(1, &token);
+    let (left, right) = (leaf(2, &token), leaf(3, &token));
+    let grandchild = leaf(4, &token);
+    adopt(&root, left.clone(), &mut token);
+    adopt(&root, right.clone(), &mut token);
+    adopt(&left, grandchild.clone(), &mut token);
+    assert_eq!(sum(&root, &token), 10);
+    assert_eq!(depth(&grandchild, &token), 2);
+
+    double(&root, &mut token);
+    assert_eq!(sum(&root, &token), 20);
+
+    reparent(&grandchild, &right, &mut token);
+    assert_eq!(sum(&left, &token), 4);
+    assert_eq!(sum(&right, &token), 14);
+    assert!(Arc::ptr_eq(
+        &grandchild.borrow(&token).parent.upgrade().unwrap(),
+        &right
+    ));
+
+    // Another token, even of the same type, can't unlock the tree.
+    assert!(root.try_borrow(&RuntimeToken::new().unwrap()).is_err());
+    println!("sum: {}", sum(&root, &token));
+}
@@ -0,0 +1,141 @@
+use core::{
+    future::Future,
+    pin::Pin,
+    task::{Context, Poll, Waker},
+};
+extern crate std;
+use std::{
+    collections::VecDeque,
+    sync::{Mutex, MutexGuard, PoisonError},
+};
+
+struct Waiters {
+    next_id: u64,
+    queue: VecDeque<(u64, Waker)>,
+}
+
+/// A FIFO of tasks waiting for a token to become available.
+///
+/// This is what powers the `acquire` function of [`singleton_token`](crate::singleton_token)s, but it can be used for custom tokens as well.
+/// ```rust
+/// # use token_cell::{prelude::*, asynchronous::WaitQueue};
+/// # use std::{future::Future, sync::Arc, task::{Context, Poll, Wake}};
+/// # struct Noop;
+/// # impl Wake for Noop { fn wake(self: Arc<Self>) {} }
+/// token_cell::singleton_token!(Key);
+/// let waker = Arc::new(Noop).into();
+/// let mut cx = Context::from_waker(&waker);
+/// let held = Key::new().unwrap();
+/// let mut acquire = Box::pin(Key::acquire());
+/// assert!(acquire.as_mut().poll(&mut cx).is_pending());
+/// drop(held);
+/// assert!(acquire.as_mut().poll(&mut cx).is_ready());
+/// ```
+pub struct WaitQueue {
+    waiters: Mutex<Waiters>,
+}
+impl WaitQueue {
+    /// Constructs an empty queue.
+    pub const fn new() -> Self {
+        WaitQueue {
+            waiters: Mutex::new(Waiters {
+                next_id: 0,
+                queue: VecDeque::new(),
+            }),
+        }
+    }
+    fn lock(&self) -> MutexGuard<'_, Waiters> {
+        self.waiters.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+    /// Constructs a future that resolves once `try_acquire` succeeds.
+    ///
+    /// `try_acquire` is retried every time the future is woken up by [`WaitQueue::notify_one`].
+    pub const fn wait<R, F: FnMut() -> Option<R> + Unpin>(&self, try_acquire: F) -> Acquire<'_, F> {
+        Acquire {
+            queue: self,
+            try_acquire,
+            id: None,
+        }
+    }
+    /// Wakes up the oldest pending [`Acquire`] future, if any.
+    ///
+    /// This should be called whenever the resource guarded by this queue becomes available.
+    pub fn notify_one(&self) {
+        let waker = self.lock().queue.pop_front();
+        if let Some((_, waker)) = waker {
+            waker.wake()
+        }
+    }
+}
+impl Default for WaitQueue {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// A future waiting for a [`WaitQueue`]'s resource to become available.
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct Acquire<'a, F> {
+    queue: &'a WaitQueue,
+    try_acquire: F,
+    id: Option<u64>,
+}
+impl<'a, R, F: FnMut() -> Option<R> + Unpin> Future for Acquire<'a, F> {
+    type Output = R;
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let this = &mut *self;
+        if let Some(acquired) = (this.try_acquire)() {
+            this.deregister();
+            return Poll::Ready(acquired);
+        }
+        {
+            let mut waiters = this.queue.lock();
+            let waiters = &mut *waiters;
+            match this
+                .id
+                .and_then(|id| waiters.queue.iter_mut().find(|(waiter, _)| *waiter == id))
+            {
+                Some((_, waker)) => waker.clone_from(cx.waker()),
+                None => {
+                    let id = *this.id.get_or_insert_with(|| {
+                        waiters.next_id += 1;
+                        waiters.next_id
+                    });
+                    waiters.queue.push_back((id, cx.waker().clone()));
+                }
+            }
+        }
+        // The resource may have been released between the first attempt and registration.
+        match (this.try_acquire)() {
+            Some(acquired) => {
+                this.deregister();
+                Poll::Ready(acquired)
+            }
+            None => Poll::Pending,
+        }
+    }
+}
+impl<'a, F> Acquire<'a, F> {
+    /// Removes this future from the queue, returning `false` if it had already been woken up.
+    fn deregister(&mut self) -> bool {
+        let Some(id) = self.id.take() else {
+            return true;
+        };
+        let mut waiters = self.queue.lock();
+        match waiters.queue.iter().position(|(waiter, _)| *waiter == id) {
+            Some(index) => {
+                waiters.queue.remove(index);
+                true
+            }
+            None => false,
+        }
+    }
+}
+impl<'a, F> Drop for Acquire<'a, F> {
+    fn drop(&mut self) {
+        // If this future was woken up but is dropped before acquiring, the notification is passed on.
+        if !self.deregister() {
+            self.queue.notify_one()
+        }
+    }
+}
@@ -1,6 +1,10 @@
 use ::core::{
     cell::UnsafeCell,
-    ops::{Deref, DerefMut},
+    convert::Infallible,
+    fmt,
+    mem::MaybeUninit,
+    ops::{ControlFlow, Deref, DerefMut, Range},
+    pin::Pin,
 };
 
 trait MapLikely<T> {
@@ -22,7 +26,7 @@ impl<T, E> MapLikely<T> for Result<T, E> {
     }
 }
 
-use crate::monads::{TokenMap, TokenMapMut};
+use crate::monads::{PendingGuardMut, TokenMap, TokenMap2, TokenMapMut, TokenMapMut2};
 /// A trait for tokens
 pub trait TokenTrait: Sized {
     /// Constructing a token may fail.
@@ -53,21 +57,102 @@ pub trait TokenTrait: Sized {
     fn with_token<R, F: for<'a> FnOnce(Self::Branded<'a>) -> R>(f: F) -> Result<R, Self::RunError>;
     /// Returns the Token's identifier, which cells may store to allow comparison.
     fn identifier(&self) -> Self::Identifier;
+    /// Consumes the Token into its identifier, e.g. to store it once the token is retired.
+    ///
+    /// Tokens whose identifier they own, such as [`arc_runtime_token`](crate::arc_runtime_token)s, may override this to move it out rather than copy it.
+    #[cfg_attr(feature = "alloc", doc = "```rust")]
+    #[cfg_attr(not(feature = "alloc"), doc = "```ignore")]
+    /// # use token_cell::prelude::*;
+    /// token_cell::arc_runtime_token!(Token);
+    /// # fn main() {
+    /// let token = Token::new().unwrap();
+    /// let cell = TokenCell::new(1, &token);
+    /// let id = token.into_identifier();
+    /// assert_eq!(std::sync::Arc::strong_count(&id), 2);
+    /// assert!(std::sync::Arc::ptr_eq(&id, cell.identifier()));
+    /// # }
+    /// ```
+    fn into_identifier(self) -> Self::Identifier {
+        self.identifier()
+    }
     /// Allows the cell to compare its identifier to the Token.
     ///
     /// # Errors
     /// If a wrong token was mistakenly passed to the cell.
     fn compare(&self, id: &Self::Identifier) -> Result<(), Self::ComparisonError>;
+    /// Produces a [`ReadOnly`] view of the token, which can only borrow cells immutably.
+    fn read_only(&self) -> ReadOnly<'_, Self> {
+        ReadOnly { token: self }
+    }
+}
+
+/// A strategy for identifying tokens at runtime, from which [`checked_token`](crate::checked_token) generates a token.
+///
+/// This separates how identity is verified from the token and the cell, e.g. to compare nonces rather than counters.
+/// ```rust
+/// # use token_cell::{prelude::*, core::TokenChecker};
+/// # use std::sync::atomic::{AtomicU64, Ordering};
+/// pub struct Nonce;
+/// unsafe impl TokenChecker for Nonce {
+///     type Identifier = u64;
+///     type Error = (u64, u64);
+///     fn identify() -> u64 {
+///         static NEXT: AtomicU64 = AtomicU64::new(0);
+///         NEXT.fetch_add(1, Ordering::Relaxed).wrapping_mul(0x9E37_79B9_7F4A_7C15)
+///     }
+///     fn check(token: &u64, cell: &u64) -> Result<(), (u64, u64)> {
+///         if token == cell { Ok(()) } else { Err((*cell, *token)) }
+///     }
+/// }
+/// token_cell::checked_token!(Key: Nonce);
+/// # fn main() {
+/// let key = Key::new().unwrap();
+/// let cell = TokenCell::new(1, &key);
+/// assert_eq!(*cell.borrow(&key), 1);
+/// assert!(cell.try_borrow(&Key::new().unwrap()).is_err());
+/// # }
+/// ```
+///
+/// # Safety
+/// [`TokenChecker::check`] may only succeed if both identifiers were produced by the same call to [`TokenChecker::identify`].
+pub unsafe trait TokenChecker {
+    /// The identifier that tokens hold and cells store.
+    type Identifier: Clone;
+    /// The error returned when a token doesn't match a cell.
+    type Error;
+    /// Produces the identifier of a newly constructed token.
+    fn identify() -> Self::Identifier;
+    /// Checks the identifier of a token against the one a cell was constructed with.
+    ///
+    /// # Errors
+    /// If the identifiers don't match.
+    fn check(token: &Self::Identifier, cell: &Self::Identifier) -> Result<(), Self::Error>;
 }
 
-/// Common ways to interract with a [`TokenCell`].
+/// Common ways to interract with a [`TokenCell`], also available as [`TokenAccess`].
 ///
 /// Note that while many functions document fallihle behaviours, this behaviour is only reachable for tokens that perform runtime check. These are identifiable by their [`TokenTrait::ComparisonError`] type not being [`core::convert::Infallible`].
-pub trait TokenCellTrait<T: ?Sized, Token: TokenTrait>: Sync {
-    /// Constructs a new cell using `token` as its key.
-    fn new(inner: T, token: &Token) -> Self
-    where
-        T: Sized;
+///
+/// This is implemented by [`TokenCell`], as well as any pointer to one, such as `&TokenCell`, `Box<TokenCell>` or `Arc<TokenCell>`.
+/// ```rust
+/// # use token_cell::prelude::*;
+/// # use std::sync::Arc;
+/// # token_cell::unsafe_token!(Token);
+/// let mut token = Token::new().unwrap();
+/// let cell = Arc::new(TokenCell::new(1, &token));
+/// *cell.borrow_mut(&mut token) += 1;
+/// assert_eq!(*cell.borrow(&token), 2);
+/// ```
+///
+/// Tokens may also be held behind any pointer or newtype that implements [`DerefMut`] to the token, since references to them coerce to references to the token.
+/// ```rust
+/// # use token_cell::{prelude::*, RuntimeToken};
+/// let mut token = Box::new(RuntimeToken::new().unwrap());
+/// let cell: TokenCell<_, RuntimeToken> = TokenCell::new(1, &token);
+/// *cell.borrow_mut(&mut token) += 1;
+/// assert_eq!(cell.map(|value| *value).try_apply(&token).ok(), Some(2));
+/// ```
+pub trait TokenCellTrait<T: ?Sized, Token: TokenTrait> {
     /// Attempts to construct a guard which [`Deref`]s to the inner data,
     /// but also allows recovering the `Token`.
     ///
@@ -99,7 +184,42 @@ pub trait TokenCellTrait<T: ?Sized, Token: TokenTrait>: Sync {
         &'l self,
         token: &'l mut Token,
     ) -> Result<&'l mut T, Token::ComparisonError>;
+    /// Borrows the inner data immutably, handing the token back so that it may keep unlocking other cells immutably.
+    /// ```rust
+    /// # use token_cell::{prelude::*, RuntimeToken};
+    /// let token = RuntimeToken::new().unwrap();
+    /// let (a, b) = (TokenCell::new(1, &token), TokenCell::new(2, &token));
+    /// let (a, token) = a.try_borrow_shared(&token).unwrap();
+    /// let (b, _) = b.try_borrow_shared(token).unwrap();
+    /// assert_eq!(a + b, 3);
+    /// ```
+    ///
+    /// # Errors
+    /// If the token provides runtime checking and detects that `self` was constructed with another token.
+    fn try_borrow_shared<'l>(
+        &'l self,
+        token: &'l Token,
+    ) -> Result<(&'l T, &'l Token), Token::ComparisonError> {
+        self.try_borrow(token).map(|inner| (inner, token))
+    }
     /// Borrows the inner data, panicking if the wrong token was used as key.
+    ///
+    /// The panic is reported at the caller's location.
+    /// ```rust
+    /// # use token_cell::{prelude::*, RuntimeToken};
+    /// # use std::sync::{Arc, Mutex};
+    /// let cell = TokenCell::new(1, &RuntimeToken::new().unwrap());
+    /// let location = Arc::new(Mutex::new(None));
+    /// let reported = location.clone();
+    /// std::panic::set_hook(Box::new(move |info| {
+    ///     *reported.lock().unwrap() = info.location().map(|location| location.line());
+    /// }));
+    /// let other = RuntimeToken::new().unwrap();
+    /// let line = line!() + 1;
+    /// assert!(std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| *cell.borrow(&other))).is_err());
+    /// assert_eq!(*location.lock().unwrap(), Some(line));
+    /// ```
+    #[track_caller]
     fn borrow<'l>(&'l self, token: &'l Token) -> &'l T
     where
         Token::ComparisonError: core::fmt::Debug,
@@ -107,6 +227,9 @@ pub trait TokenCellTrait<T: ?Sized, Token: TokenTrait>: Sync {
         self.try_borrow(token).unwrap()
     }
     /// Borrows the inner data mutably, panicking if the wrong token was used as key.
+    ///
+    /// The panic is reported at the caller's location.
+    #[track_caller]
     fn borrow_mut<'l>(&'l self, token: &'l mut Token) -> &'l mut T
     where
         Token::ComparisonError: core::fmt::Debug,
@@ -135,6 +258,816 @@ pub trait TokenCellTrait<T: ?Sized, Token: TokenTrait>: Sync {
             marker: core::marker::PhantomData,
         }
     }
+    /// Constructs a lazy computation over both `self` and `other`, that can then be applied using a single token.
+    /// ```rust
+    /// # use token_cell::{prelude::*, RuntimeToken};
+    /// let token = RuntimeToken::new().unwrap();
+    /// let (a, b) = (TokenCell::new(1, &token), TokenCell::new(2, &token));
+    /// let sum = a.map2(&b, |a, b| *a + *b);
+    /// assert_eq!(sum.try_apply(&token).ok(), Some(3));
+    /// ```
+    fn map2<
+        'a,
+        T2: ?Sized,
+        U,
+        Cell2: TokenAccess<T2, Token> + ?Sized,
+        F: FnOnce(TokenGuard<'a, T, Token>, TokenGuard<'a, T2, Token>) -> U,
+    >(
+        &'a self,
+        other: &'a Cell2,
+        f: F,
+    ) -> TokenMap2<'a, T, T2, U, F, Self, Cell2, Token> {
+        TokenMap2 {
+            cell: self,
+            other,
+            f,
+            marker: core::marker::PhantomData,
+        }
+    }
+    /// Constructs a lazy computation over both `self` and `other` borrowed mutably, that can then be applied using a single token.
+    ///
+    /// Applying it panics if the contents of both cells overlap, such as when they are the same cell.
+    /// ```rust
+    /// # use token_cell::{prelude::*, RuntimeToken};
+    /// let mut token = RuntimeToken::new().unwrap();
+    /// let (a, b) = (TokenCell::new(1, &token), TokenCell::new(2, &token));
+    /// a.map2_mut(&b, core::mem::swap).try_apply(&mut token).ok().unwrap();
+    /// assert_eq!(*a.borrow(&token), 2);
+    /// ```
+    fn map2_mut<
+        'a,
+        T2: ?Sized,
+        U,
+        Cell2: TokenAccess<T2, Token> + ?Sized,
+        F: FnOnce(&'a mut T, &'a mut T2) -> U,
+    >(
+        &'a self,
+        other: &'a Cell2,
+        f: F,
+    ) -> TokenMapMut2<'a, T, T2, U, F, Self, Cell2, Token> {
+        TokenMapMut2 {
+            cell: self,
+            other,
+            f,
+            marker: core::marker::PhantomData,
+        }
+    }
+    /// Feeds the inner data into `state`.
+    ///
+    /// The token's identifier isn't hashed, so cells with equal contents hash equally regardless of their tokens, consistently with comparing their contents for equality.
+    ///
+    /// # Errors
+    /// If the token provides runtime checking and detects that `self` was constructed with another token.
+    fn hash_with<H: core::hash::Hasher>(
+        &self,
+        token: &Token,
+        state: &mut H,
+    ) -> Result<(), Token::ComparisonError>
+    where
+        T: core::hash::Hash,
+    {
+        self.try_borrow(token).map(|inner| inner.hash(state))
+    }
+    /// Compares the inner data of `self` and `other`.
+    /// ```rust
+    /// # use token_cell::{prelude::*, RuntimeToken};
+    /// let token = RuntimeToken::new().unwrap();
+    /// let mut cells = vec![TokenCell::new(2, &token), TokenCell::new(1, &token)];
+    /// cells.sort_by(|a, b| a.cmp_with(b, &token).unwrap());
+    /// assert_eq!(*cells[0].borrow(&token), 1);
+    /// ```
+    ///
+    /// # Errors
+    /// If the token provides runtime checking and detects that either cell was constructed with another token.
+    fn cmp_with(
+        &self,
+        other: &Self,
+        token: &Token,
+    ) -> Result<core::cmp::Ordering, Token::ComparisonError>
+    where
+        T: Ord,
+    {
+        Ok(self.try_borrow(token)?.cmp(other.try_borrow(token)?))
+    }
+    /// Runs `f` on the inner data borrowed mutably, releasing the token as soon as `f` returns.
+    /// ```rust
+    /// # use token_cell::prelude::*;
+    /// # token_cell::unsafe_token!(Token);
+    /// let mut token = Token::new().unwrap();
+    /// let (a, b) = (TokenCell::new(1, &token), TokenCell::new(2, &token));
+    /// let previous = a.with_mut(&mut token, |a| core::mem::replace(a, 3)).unwrap();
+    /// b.with_mut(&mut token, |b| *b += previous).unwrap();
+    /// assert_eq!((*a.borrow(&token), *b.borrow(&token)), (3, 3));
+    /// ```
+    ///
+    /// # Errors
+    /// If the token provides runtime checking and detects that `self` was constructed with another token.
+    fn with_mut<R, F: FnOnce(&mut T) -> R>(
+        &self,
+        token: &mut Token,
+        f: F,
+    ) -> Result<R, Token::ComparisonError> {
+        self.try_borrow_mut(token).map(f)
+    }
+    /// Runs `f` on the inner data borrowed mutably with a token passed by value, handing the token back afterwards.
+    ///
+    /// `f` doesn't get access to the token, as it could otherwise borrow this cell a second time.
+    /// ```rust
+    /// # use token_cell::prelude::*;
+    /// # token_cell::unsafe_token!(Token);
+    /// let cell = TokenCell::new(1, &Token::new().unwrap());
+    /// let (token, result) = cell.with_owned(Token::new().unwrap(), |value| *value += 1);
+    /// result.unwrap();
+    /// assert_eq!(*cell.borrow(&token), 2);
+    /// ```
+    fn with_owned<R, F: FnOnce(&mut T) -> R>(
+        &self,
+        mut token: Token,
+        f: F,
+    ) -> (Token, Result<R, Token::ComparisonError>) {
+        let result = self.with_mut(&mut token, f);
+        (token, result)
+    }
+    /// Replaces the inner data with the result of `f` on it, returning the previous value, similarly to [`core::cell::RefCell::replace_with`].
+    ///
+    /// `f` is only called once the token has been checked.
+    /// ```rust
+    /// # use token_cell::prelude::*;
+    /// # token_cell::unsafe_token!(Token);
+    /// let mut token = Token::new().unwrap();
+    /// let cell = TokenCell::new(1, &token);
+    /// assert_eq!(cell.replace_with(&mut token, |state| *state + 1).unwrap(), 1);
+    /// assert_eq!(*cell.borrow(&token), 2);
+    /// ```
+    ///
+    /// # Errors
+    /// If the token provides runtime checking and detects that `self` was constructed with another token.
+    fn replace_with<F: FnOnce(&mut T) -> T>(
+        &self,
+        token: &mut Token,
+        f: F,
+    ) -> Result<T, Token::ComparisonError>
+    where
+        T: Sized,
+    {
+        self.try_borrow_mut(token).map(|inner| {
+            let replacement = f(inner);
+            core::mem::replace(inner, replacement)
+        })
+    }
+    /// Replaces the inner data with `value`, returning the previous value, similarly to [`core::cell::RefCell::replace`].
+    ///
+    /// Unlike taking the value out, this doesn't require `T: Default`.
+    /// ```rust
+    /// # use token_cell::prelude::*;
+    /// # token_cell::unsafe_token!(Token);
+    /// let mut token = Token::new().unwrap();
+    /// let cell = TokenCell::new(String::from("old"), &token);
+    /// assert_eq!(cell.replace(String::from("new"), &mut token).unwrap(), "old");
+    /// assert_eq!(cell.borrow(&token), "new");
+    /// ```
+    ///
+    /// # Errors
+    /// If the token provides runtime checking and detects that `self` was constructed with another token.
+    fn replace(&self, value: T, token: &mut Token) -> Result<T, Token::ComparisonError>
+    where
+        T: Sized,
+    {
+        self.try_borrow_mut(token)
+            .map(|inner| core::mem::replace(inner, value))
+    }
+    /// Swaps the inner data with `value` in place, similarly to [`core::cell::RefCell::swap`].
+    ///
+    /// This avoids moving the value in and out of the cell, which may be costly for large `T`s.
+    /// ```rust
+    /// # use token_cell::prelude::*;
+    /// # token_cell::unsafe_token!(Token);
+    /// let mut token = Token::new().unwrap();
+    /// let cell = TokenCell::new([1u8; 4096], &token);
+    /// let mut buffer = [2u8; 4096];
+    /// cell.swap_value(&mut buffer, &mut token).unwrap();
+    /// assert_eq!((buffer[0], cell.borrow(&token)[0]), (1, 2));
+    /// ```
+    ///
+    /// # Errors
+    /// If the token provides runtime checking and detects that `self` was constructed with another token.
+    fn swap_value(&self, value: &mut T, token: &mut Token) -> Result<(), Token::ComparisonError>
+    where
+        T: Sized,
+    {
+        self.try_borrow_mut(token)
+            .map(|inner| core::mem::swap(inner, value))
+    }
+    /// Constructs a mutable guard that will be acquired once the token is provided.
+    ///
+    /// This lets you decide which cell to lock before the token is available.
+    fn guard_later(&self) -> PendingGuardMut<'_, T, Self, Token> {
+        PendingGuardMut {
+            cell: self,
+            marker: core::marker::PhantomData,
+        }
+    }
+}
+
+/// [`TokenCellTrait`] under the name it's implemented with by pointers and wrappers, which may access cells but not construct them like [`TokenConstruct`]s.
+pub use TokenCellTrait as TokenAccess;
+
+/// Ways to construct a [`TokenCell`].
+pub trait TokenConstruct<T: ?Sized, Token: TokenTrait>: TokenAccess<T, Token> {
+    /// Constructs a new cell using `token` as its key.
+    fn new(inner: T, token: &Token) -> Self
+    where
+        T: Sized;
+    /// Constructs a new cell using `token` as its key, if `inner` was successfully constructed.
+    /// ```rust
+    /// # use token_cell::prelude::*;
+    /// # token_cell::unsafe_token!(Token);
+    /// let token = Token::new().unwrap();
+    /// let cell = TokenCell::try_new("1".parse::<u32>(), &token).unwrap();
+    /// assert_eq!(*cell.borrow(&token), 1);
+    /// ```
+    ///
+    /// # Errors
+    /// If `inner` is an error, which is returned as is.
+    fn try_new<E>(inner: Result<T, E>, token: &Token) -> Result<Self, E>
+    where
+        T: Sized,
+        Self: Sized,
+    {
+        inner.map(|inner| Self::new(inner, token))
+    }
+}
+
+/// The result of checking a `Token` against an identifier.
+///
+/// Returning this from code that's generic over the token lets `?` propagate comparison failures uniformly,
+/// whether they are [`Infallible`] (as for [`GhostToken`](crate::ghost::GhostToken)s) or checked at runtime.
+pub type ComparisonResult<T, Token> = Result<T, <Token as TokenTrait>::ComparisonError>;
+
+/// Fails compilation when used in a `const` context if `Token` isn't zero-sized, guarding against accidentally picking a runtime-checked token.
+/// ```rust
+/// # use token_cell::{prelude::*, core::assert_zst};
+/// # token_cell::unsafe_token!(Token);
+/// const _: () = assert_zst::<Token>();
+/// ```
+/// ```compile_fail
+/// # use token_cell::{core::assert_zst, RuntimeToken};
+/// const _: () = assert_zst::<RuntimeToken>();
+/// ```
+///
+/// # Panics
+/// If `Token` isn't zero-sized.
+pub const fn assert_zst<Token: TokenTrait>() {
+    assert!(
+        core::mem::size_of::<Token>() == 0,
+        "this token type isn't zero-sized"
+    );
+}
+
+/// Any of the errors a `Token` may produce, which lets code that's generic over the token surface them as a single type.
+///
+/// Since the three error types may be the same, this can't implement [`From`] for each of them: use the variants as conversion functions instead.
+/// ```rust
+/// # use token_cell::{prelude::*, core::TokenError};
+/// fn read<Token: TokenTrait>() -> Result<u32, TokenError<Token>> {
+///     let token = Token::new().map_err(TokenError::Construction)?;
+///     let cell = TokenCell::new(1, &token);
+///     cell.try_borrow(&token).copied().map_err(TokenError::Comparison)
+/// }
+/// token_cell::singleton_token!(Key);
+/// let held = Key::new().unwrap();
+/// assert!(matches!(read::<Key>(), Err(TokenError::Construction(_))));
+/// drop(held);
+/// assert_eq!(read::<Key>().unwrap(), 1);
+/// ```
+pub enum TokenError<Token: TokenTrait> {
+    /// The token couldn't be constructed.
+    Construction(Token::ConstructionError),
+    /// The token couldn't be run.
+    Run(Token::RunError),
+    /// The token didn't match a cell's identifier.
+    Comparison(Token::ComparisonError),
+}
+impl<Token: TokenTrait> fmt::Debug for TokenError<Token>
+where
+    Token::ConstructionError: fmt::Debug,
+    Token::RunError: fmt::Debug,
+    Token::ComparisonError: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TokenError::Construction(e) => f.debug_tuple("Construction").field(e).finish(),
+            TokenError::Run(e) => f.debug_tuple("Run").field(e).finish(),
+            TokenError::Comparison(e) => f.debug_tuple("Comparison").field(e).finish(),
+        }
+    }
+}
+impl<Token: TokenTrait> fmt::Display for TokenError<Token>
+where
+    Token::ConstructionError: fmt::Display,
+    Token::RunError: fmt::Display,
+    Token::ComparisonError: fmt::Display,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TokenError::Construction(e) => e.fmt(f),
+            TokenError::Run(e) => e.fmt(f),
+            TokenError::Comparison(e) => e.fmt(f),
+        }
+    }
+}
+
+/// Checks that `token` matches `id`, the identifier a cell was constructed with.
+///
+/// This is [`TokenTrait::compare`] as a free function, making it convenient to use in code that's generic over the token.
+/// ```rust
+/// # use token_cell::{prelude::*, core::{check, ComparisonResult}, ghost::GhostToken, RuntimeToken};
+/// fn sum<Token: TokenTrait>(cells: &[TokenCell<u32, Token>], token: &Token) -> ComparisonResult<u32, Token> {
+///     let mut sum = 0;
+///     for cell in cells {
+///         sum += *cell.try_borrow(token)?;
+///     }
+///     Ok(sum)
+/// }
+/// let token = RuntimeToken::new().unwrap();
+/// let cells = [TokenCell::new(1, &token), TokenCell::new(2, &token)];
+/// assert_eq!(sum(&cells, &token).unwrap(), 3);
+/// assert!(check(&RuntimeToken::new().unwrap(), &token.identifier()).is_err());
+/// GhostToken::with_token(|token| {
+///     let cells = [TokenCell::new(1, &token), TokenCell::new(2, &token)];
+///     let Ok(sum) = sum(&cells, &token);
+///     assert_eq!(sum, 3);
+/// })
+/// .unwrap();
+/// ```
+///
+/// # Errors
+/// If the token provides runtime checking and detects that `id` isn't its identifier.
+pub fn check<Token: TokenTrait>(
+    token: &Token,
+    id: &Token::Identifier,
+) -> ComparisonResult<(), Token> {
+    token.compare(id)
+}
+
+/// Borrows `read` immutably and `write` mutably with the same `token`.
+/// ```rust
+/// # use token_cell::{prelude::*, core::borrow_pair, RuntimeToken};
+/// let mut token = RuntimeToken::new().unwrap();
+/// let (source, destination) = (TokenCell::new(1, &token), TokenCell::new(0, &token));
+/// let (source, destination) = borrow_pair(&source, &destination, &mut token).unwrap();
+/// *destination = *source;
+/// assert_eq!(*destination, 1);
+/// ```
+///
+/// # Errors
+/// If the token provides runtime checking and detects that either cell was constructed with another token.
+///
+/// # Panics
+/// If the contents of `read` and `write` overlap, such as when they are the same cell, after the token has been checked against both.
+/// Zero-sized contents at the same address are considered overlapping.
+/// ```should_panic
+/// # use token_cell::{prelude::*, core::borrow_pair, RuntimeToken};
+/// token_cell::unsafe_token!(Inner);
+/// # fn main() {
+/// let mut token = RuntimeToken::new().unwrap();
+/// let holder = TokenCell::new(Inner::new().unwrap(), &token);
+/// let _ = borrow_pair(&holder, &holder, &mut token);
+/// # }
+/// ```
+pub fn borrow_pair<'l, A: ?Sized, B: ?Sized, Token: TokenTrait>(
+    read: &'l TokenCell<A, Token>,
+    write: &'l TokenCell<B, Token>,
+    token: &'l mut Token,
+) -> ComparisonResult<(&'l A, &'l mut B), Token> {
+    token.compare(&read.token_id)?;
+    token.compare(&write.token_id)?;
+    assert!(
+        !overlap(read.contents(), write.contents()),
+        "borrow_pair called with overlapping cells"
+    );
+    Ok(unsafe { (&*read.inner.get(), &mut *write.inner.get()) })
+}
+
+/// The range of addresses `value` spans.
+pub(crate) fn contents<T: ?Sized>(value: &T) -> Range<usize> {
+    let start = value as *const T as *const u8 as usize;
+    start..start + core::mem::size_of_val(value)
+}
+
+/// Whether two borrows of these address ranges may alias.
+///
+/// Zero-sized contents at the same address count as overlapping, as they may be the same cell: a token stored in a cell would otherwise be borrowed mutably twice.
+pub(crate) const fn overlap(a: Range<usize>, b: Range<usize>) -> bool {
+    a.start == b.start || (a.start < b.end && b.start < a.end)
+}
+
+/// A token lent to [`TokenCell::scope_mut`]'s closure, which can borrow any cell but the one it's scoped to immutably.
+pub struct TokenReborrow<'a, Token: TokenTrait> {
+    token: &'a Token,
+    excluded: Range<usize>,
+}
+impl<'a, Token: TokenTrait> TokenReborrow<'a, Token> {
+    /// Borrows `cell` immutably.
+    ///
+    /// # Errors
+    /// If the token provides runtime checking and detects that `cell` was constructed with another token.
+    ///
+    /// # Panics
+    /// If the contents of `cell` overlap those of the mutably borrowed cell, such as when they are the same cell, after the token has been checked.
+    /// Zero-sized contents at the same address are considered overlapping.
+    /// ```should_panic
+    /// # use token_cell::{prelude::*, RuntimeToken};
+    /// token_cell::unsafe_token!(Inner);
+    /// # fn main() {
+    /// let mut token = RuntimeToken::new().unwrap();
+    /// let holder = TokenCell::new(Inner::new().unwrap(), &token);
+    /// let _ = holder.scope_mut(&mut token, |_, token| {
+    ///     let _ = token.try_borrow(&holder);
+    /// });
+    /// # }
+    /// ```
+    pub fn try_borrow<'b, U: ?Sized>(
+        &'b self,
+        cell: &'b TokenCell<U, Token>,
+    ) -> ComparisonResult<&'b U, Token> {
+        self.token.compare(&cell.token_id)?;
+        assert!(
+            !overlap(self.excluded.clone(), cell.contents()),
+            "TokenReborrow used on the mutably borrowed cell"
+        );
+        Ok(unsafe { &*cell.inner.get() })
+    }
+}
+
+/// A token lent to [`TokenCell::with_mut_reentrant`]'s closure, which can borrow any cell but the one it's scoped to, including mutably.
+pub struct TokenReborrowMut<'a, Token: TokenTrait> {
+    token: &'a mut Token,
+    excluded: Range<usize>,
+}
+impl<'a, Token: TokenTrait> TokenReborrowMut<'a, Token> {
+    /// Borrows `cell` immutably.
+    ///
+    /// # Errors
+    /// If the token provides runtime checking and detects that `cell` was constructed with another token.
+    ///
+    /// # Panics
+    /// If the contents of `cell` overlap those of the mutably borrowed cell, such as when they are the same cell, after the token has been checked.
+    pub fn try_borrow<'b, U: ?Sized>(
+        &'b self,
+        cell: &'b TokenCell<U, Token>,
+    ) -> ComparisonResult<&'b U, Token> {
+        self.token.compare(&cell.token_id)?;
+        assert!(
+            !overlap(self.excluded.clone(), cell.contents()),
+            "TokenReborrowMut used on the mutably borrowed cell"
+        );
+        Ok(unsafe { &*cell.inner.get() })
+    }
+    /// Borrows `cell` mutably, which keeps the reborrow itself borrowed until the returned reference is dropped.
+    ///
+    /// # Errors
+    /// If the token provides runtime checking and detects that `cell` was constructed with another token.
+    ///
+    /// # Panics
+    /// If the contents of `cell` overlap those of the mutably borrowed cell, such as when they are the same cell, after the token has been checked.
+    /// Zero-sized contents at the same address are considered overlapping.
+    /// ```should_panic
+    /// # use token_cell::{prelude::*, RuntimeToken};
+    /// # use core::ops::ControlFlow;
+    /// token_cell::unsafe_token!(Inner);
+    /// # fn main() {
+    /// let mut token = RuntimeToken::new().unwrap();
+    /// let holder = TokenCell::new(Inner::new().unwrap(), &token);
+    /// let _ = holder.with_mut_reentrant(&mut token, |_, mut token| {
+    ///     let _ = token.try_borrow_mut(&holder);
+    ///     ControlFlow::Break(())
+    /// });
+    /// # }
+    /// ```
+    pub fn try_borrow_mut<'b, U: ?Sized>(
+        &'b mut self,
+        cell: &'b TokenCell<U, Token>,
+    ) -> ComparisonResult<&'b mut U, Token> {
+        self.token.compare(&cell.token_id)?;
+        assert!(
+            !overlap(self.excluded.clone(), cell.contents()),
+            "TokenReborrowMut used on the mutably borrowed cell"
+        );
+        Ok(unsafe { &mut *cell.inner.get() })
+    }
+}
+
+/// A read capability for cells, produced by [`TokenTrait::read_only`].
+///
+/// Handing one to a subsystem lets it read cells while the token itself, and the ability to write to them, stays with its owner.
+/// ```rust
+/// # use token_cell::{prelude::*, core::ReadOnly, RuntimeToken};
+/// fn total(cells: &[TokenCell<u32, RuntimeToken>], reader: ReadOnly<'_, RuntimeToken>) -> u32 {
+///     cells.iter().map(|cell| *reader.try_borrow(cell).unwrap()).sum()
+/// }
+/// let mut token = RuntimeToken::new().unwrap();
+/// let cells = [TokenCell::new(1, &token), TokenCell::new(2, &token)];
+/// *cells[0].borrow_mut(&mut token) += 1;
+/// assert_eq!(total(&cells, token.read_only()), 4);
+/// ```
+pub struct ReadOnly<'a, Token: TokenTrait> {
+    token: &'a Token,
+}
+impl<'a, Token: TokenTrait> ReadOnly<'a, Token> {
+    /// Borrows `cell` immutably.
+    ///
+    /// # Errors
+    /// If the token provides runtime checking and detects that `cell` was constructed with another token.
+    pub fn try_borrow<'b, U: ?Sized, Cell: TokenAccess<U, Token> + ?Sized>(
+        &self,
+        cell: &'b Cell,
+    ) -> ComparisonResult<&'b U, Token>
+    where
+        'a: 'b,
+    {
+        cell.try_borrow(self.token)
+    }
+}
+impl<'a, Token: TokenTrait> Clone for ReadOnly<'a, Token> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+impl<'a, Token: TokenTrait> Copy for ReadOnly<'a, Token> {}
+
+/// Read access to a cell's contents, produced by [`TokenCell::read_view`], whose type doesn't mention the token.
+///
+/// This lets library boundaries accept read access without being generic over tokens.
+/// ```rust
+/// # use token_cell::{prelude::*, core::ReadView, RuntimeToken};
+/// fn len(view: ReadView<'_, str>) -> usize {
+///     view.len()
+/// }
+/// let token = RuntimeToken::new().unwrap();
+/// let cell = TokenCell::new(String::from("hello"), &token);
+/// let view = cell.read_view(&token).unwrap();
+/// assert_eq!(len(view.map(String::as_str)), 5);
+/// ```
+pub struct ReadView<'a, T: ?Sized> {
+    inner: &'a T,
+}
+impl<'a, T: ?Sized> ReadView<'a, T> {
+    /// Keeps only the borrow of the contents, whose lifetime is no longer tied to the view.
+    pub const fn get(self) -> &'a T {
+        self.inner
+    }
+    /// Narrows the view to a part of the contents, similarly to [`core::cell::Ref::map`].
+    pub fn map<U: ?Sized, F: FnOnce(&'a T) -> &'a U>(self, f: F) -> ReadView<'a, U> {
+        ReadView {
+            inner: f(self.inner),
+        }
+    }
+}
+impl<'a, T: ?Sized> Clone for ReadView<'a, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+impl<'a, T: ?Sized> Copy for ReadView<'a, T> {}
+impl<'a, T: ?Sized> Deref for ReadView<'a, T> {
+    type Target = T;
+    fn deref(&self) -> &Self::Target {
+        self.inner
+    }
+}
+impl<'a, T: ?Sized, Token: TokenTrait> From<TokenGuard<'a, T, Token>> for ReadView<'a, T> {
+    fn from(guard: TokenGuard<'a, T, Token>) -> Self {
+        ReadView {
+            inner: guard.leak(),
+        }
+    }
+}
+
+/// Borrows each of `cells` with `token`.
+/// ```rust
+/// # use token_cell::{prelude::*, core::iter_borrowed, RuntimeToken};
+/// let token = RuntimeToken::new().unwrap();
+/// let cells = [TokenCell::new(1, &token), TokenCell::new(2, &token)];
+/// let sum: Result<i32, _> = iter_borrowed(&cells, &token).sum();
+/// assert_eq!(sum.unwrap(), 3);
+/// ```
+pub fn iter_borrowed<'a, T, Token: TokenTrait>(
+    cells: &'a [TokenCell<T, Token>],
+    token: &'a Token,
+) -> impl Iterator<Item = Result<&'a T, Token::ComparisonError>> + 'a {
+    cells.iter().map(move |cell| cell.try_borrow(token))
+}
+/// Borrows each of `cells` with `token`, which never fails for tokens whose comparison is infallible.
+/// ```rust
+/// # use token_cell::{prelude::*, core::iter_borrowed_unchecked};
+/// # token_cell::unsafe_token!(Token);
+/// let token = Token::new().unwrap();
+/// let cells = [TokenCell::new(1, &token), TokenCell::new(2, &token)];
+/// assert_eq!(iter_borrowed_unchecked(&cells, &token).sum::<i32>(), 3);
+/// ```
+pub fn iter_borrowed_unchecked<'a, T, Token: TokenTrait<ComparisonError = Infallible>>(
+    cells: &'a [TokenCell<T, Token>],
+    token: &'a Token,
+) -> impl Iterator<Item = &'a T> + 'a {
+    cells.iter().map(move |cell| match cell.try_borrow(token) {
+        Ok(borrowed) => borrowed,
+        Err(e) => match e {},
+    })
+}
+
+/// With the `alloc` feature, borrows each of `cells` mutably with `token` at once.
+///
+/// Cells of a slice are disjoint, so handing out a mutable reference to each of them is sound once they've all been checked against `token`.
+/// ```rust
+/// # use token_cell::{prelude::*, core::borrow_many_mut, RuntimeToken};
+/// let mut token = RuntimeToken::new().unwrap();
+/// let cells = [TokenCell::new(1, &token), TokenCell::new(2, &token)];
+/// if let [a, b] = &mut borrow_many_mut(&cells, &mut token).unwrap()[..] {
+///     core::mem::swap(*a, *b);
+/// }
+/// assert_eq!(*cells[0].borrow(&token), 2);
+/// ```
+///
+/// # Errors
+/// If the token provides runtime checking and detects that any of `cells` was constructed with another token.
+#[cfg(feature = "alloc")]
+pub fn borrow_many_mut<'a, T, Token: TokenTrait>(
+    cells: &'a [TokenCell<T, Token>],
+    token: &'a mut Token,
+) -> Result<alloc::vec::Vec<&'a mut T>, Token::ComparisonError> {
+    for cell in cells {
+        token.compare(&cell.token_id)?;
+    }
+    Ok(cells
+        .iter()
+        .map(|cell| unsafe { &mut *cell.inner.get() })
+        .collect())
+}
+
+/// The state of a [`borrow_mut`](crate::borrow_mut) invocation, which remembers the contents of the `N` cells it may claim so that none are claimed twice.
+#[doc(hidden)]
+pub struct BorrowMany<'a, Token: TokenTrait, const N: usize> {
+    token: &'a mut Token,
+    claimed: [Range<usize>; N],
+    len: usize,
+}
+impl<'a, Token: TokenTrait, const N: usize> BorrowMany<'a, Token, N> {
+    pub const fn new(token: &'a mut Token) -> Self {
+        const UNCLAIMED: Range<usize> = 0..0;
+        BorrowMany {
+            token,
+            claimed: [UNCLAIMED; N],
+            len: 0,
+        }
+    }
+    /// # Errors
+    /// If the token provides runtime checking and detects that `cell` was constructed with another token.
+    ///
+    /// # Panics
+    /// If the contents of `cell` overlap those of a previously claimed cell, or if more than `N` cells are claimed.
+    #[allow(clippy::mut_from_ref)]
+    pub fn claim<T: ?Sized>(
+        &mut self,
+        cell: &'a TokenCell<T, Token>,
+    ) -> ComparisonResult<&'a mut T, Token> {
+        self.token.compare(&cell.token_id)?;
+        let contents = cell.contents();
+        assert!(
+            !self.claimed[..self.len]
+                .iter()
+                .any(|claimed| overlap(claimed.clone(), contents.clone())),
+            "borrow_mut called with overlapping cells"
+        );
+        self.claimed[self.len] = contents;
+        self.len += 1;
+        Ok(unsafe { &mut *cell.inner.get() })
+    }
+}
+
+/// With the `std` feature, borrows each of the cells in `map` mutably with `token`.
+///
+/// The values of a map are distinct cells, so each of them may be borrowed mutably for as long as `token` is, once it's been checked against `token`.
+/// ```rust
+/// # use token_cell::{prelude::*, core::values_mut_borrowed, RuntimeToken};
+/// # use std::collections::HashMap;
+/// let mut token = RuntimeToken::new().unwrap();
+/// let map: HashMap<_, _> = (0..3).map(|i| (i, TokenCell::new(i, &token))).collect();
+/// for value in values_mut_borrowed(&map, &mut token) {
+///     *value.unwrap() *= 2;
+/// }
+/// assert_eq!(*map[&2].borrow(&token), 4);
+/// ```
+#[cfg(feature = "std")]
+pub fn values_mut_borrowed<'a, K, V, S, Token: TokenTrait>(
+    map: &'a ::std::collections::HashMap<K, TokenCell<V, Token>, S>,
+    token: &'a mut Token,
+) -> impl Iterator<Item = Result<&'a mut V, Token::ComparisonError>> + 'a {
+    let token: &'a Token = token;
+    map.values().map(move |cell| {
+        token
+            .compare(&cell.token_id)
+            .map(|()| unsafe { &mut *cell.inner.get() })
+    })
+}
+/// With the `std` feature, borrows each of the cells in `map` mutably with `token`, along with its key.
+///
+/// Like with [`values_mut_borrowed`], the borrows may all be held at once, since each of them is of a distinct cell.
+/// ```rust
+/// # use token_cell::{prelude::*, core::iter_borrowed_mut, RuntimeToken};
+/// # use std::collections::HashMap;
+/// let mut token = RuntimeToken::new().unwrap();
+/// let map: HashMap<_, _> = (1..4).map(|i| (i, TokenCell::new(0, &token))).collect();
+/// let borrowed: Vec<_> = iter_borrowed_mut(&map, &mut token).collect();
+/// for (key, value) in borrowed {
+///     *value.unwrap() = *key * 10;
+/// }
+/// assert_eq!(*map[&3].borrow(&token), 30);
+/// ```
+#[cfg(feature = "std")]
+pub fn iter_borrowed_mut<'a, K, V, S, Token: TokenTrait>(
+    map: &'a ::std::collections::HashMap<K, TokenCell<V, Token>, S>,
+    token: &'a mut Token,
+) -> impl Iterator<Item = (&'a K, Result<&'a mut V, Token::ComparisonError>)> + 'a {
+    let token: &'a Token = token;
+    map.iter().map(move |(key, cell)| {
+        (
+            key,
+            token
+                .compare(&cell.token_id)
+                .map(|()| unsafe { &mut *cell.inner.get() }),
+        )
+    })
+}
+
+/// Iterates mutably over the contents of `cells`.
+///
+/// Exclusive access to the slice is already proof of exclusive access to each cell, so no token is needed.
+/// ```rust
+/// # use token_cell::{prelude::*, core::get_all_mut};
+/// # token_cell::unsafe_token!(Token);
+/// let token = Token::new().unwrap();
+/// let mut cells = [TokenCell::new(1, &token), TokenCell::new(2, &token)];
+/// get_all_mut(&mut cells).for_each(|value| *value *= 2);
+/// assert_eq!(*cells[1].borrow(&token), 4);
+/// ```
+pub fn get_all_mut<T, Token: TokenTrait>(
+    cells: &mut [TokenCell<T, Token>],
+) -> impl Iterator<Item = &mut T> {
+    cells.iter_mut().map(TokenCell::get_mut)
+}
+/// Borrows the contents of each of `cells` mutably, which needs no token for the same reasons as [`get_all_mut`].
+/// ```rust
+/// # use token_cell::{prelude::*, core::get_all_mut_array};
+/// # token_cell::unsafe_token!(Token);
+/// let token = Token::new().unwrap();
+/// let mut cells = [TokenCell::new(1, &token), TokenCell::new(2, &token)];
+/// let [a, b] = get_all_mut_array(&mut cells);
+/// core::mem::swap(a, b);
+/// assert_eq!(*cells[0].borrow(&token), 2);
+/// ```
+pub fn get_all_mut_array<T, Token: TokenTrait, const N: usize>(
+    cells: &mut [TokenCell<T, Token>; N],
+) -> [&mut T; N] {
+    cells.each_mut().map(TokenCell::get_mut)
+}
+
+/// Sorts `cells` by their contents, without preserving the order of equal contents like [`slice::sort_unstable`].
+///
+/// Like with [`get_all_mut`], exclusive access to the slice is proof of exclusive access to each cell, so no token is needed.
+/// ```rust
+/// # use token_cell::{prelude::*, core::sort_cells};
+/// # token_cell::unsafe_token!(Token);
+/// let token = Token::new().unwrap();
+/// let mut cells = [TokenCell::new(3, &token), TokenCell::new(1, &token), TokenCell::new(2, &token)];
+/// sort_cells(&mut cells);
+/// assert_eq!(*cells[0].borrow(&token), 1);
+/// ```
+pub fn sort_cells<T: Ord, Token: TokenTrait>(cells: &mut [TokenCell<T, Token>]) {
+    cells.sort_unstable_by(|a, b| unsafe { (*a.inner.get()).cmp(&*b.inner.get()) })
+}
+
+/// With the `rayon` feature, iterates mutably over the contents of `cells` in parallel.
+///
+/// This is the "I own the cells" fast path: exclusive access to the slice is already proof of exclusive access to each cell, so no token is needed.
+/// ```rust
+/// # use token_cell::{prelude::*, core::par_iter_mut};
+/// # use rayon::prelude::*;
+/// # token_cell::unsafe_token!(Token);
+/// let token = Token::new().unwrap();
+/// let mut cells: Vec<_> = (0..100).map(|i| TokenCell::new(i, &token)).collect();
+/// par_iter_mut(&mut cells).for_each(|value| *value *= 2);
+/// assert_eq!(*cells[50].borrow(&token), 100);
+/// ```
+#[cfg(feature = "rayon")]
+pub fn par_iter_mut<T: Send, Token: TokenTrait>(
+    cells: &mut [TokenCell<T, Token>],
+) -> impl rayon::iter::IndexedParallelIterator<Item = &mut T>
+where
+    Token::Identifier: Send,
+{
+    use rayon::prelude::*;
+    cells.par_iter_mut().map(TokenCell::get_mut)
 }
 
 /// A guard that allows immutably borrowing the cell's value, as well as its token.
@@ -143,10 +1076,42 @@ pub struct TokenGuard<'a, T: ?Sized, Token: TokenTrait> {
     token: &'a Token,
 }
 impl<'a, T: ?Sized, Token: TokenTrait> TokenGuard<'a, T, Token> {
+    /// Constructs a guard over `cell`, which is useful to implement [`TokenAccess`] for wrappers around [`TokenCell`].
+    /// ```rust
+    /// # use token_cell::{prelude::*, core::TokenGuard};
+    /// # token_cell::unsafe_token!(Token);
+    /// let token = Token::new().unwrap();
+    /// let cell = TokenCell::new(1, &token);
+    /// let guard = unsafe { TokenGuard::from_parts(&cell, &token) };
+    /// assert_eq!(*guard, 1);
+    /// ```
+    ///
+    /// # Safety
+    /// `token` must have been successfully compared against the identifier `cell` was constructed with.
+    pub const unsafe fn from_parts(cell: &'a TokenCell<T, Token>, token: &'a Token) -> Self {
+        TokenGuard { cell, token }
+    }
     /// Reborrows the token immutably.
     pub const fn token(&self) -> &Token {
         self.token
     }
+    /// The identifier the guarded cell was constructed with, as returned by [`TokenCell::identifier`].
+    pub const fn cell_identifier(&self) -> &Token::Identifier {
+        &self.cell.token_id
+    }
+    /// Consumes the guard, keeping only the borrow of the cell's value, similarly to [`core::cell::Ref::leak`].
+    ///
+    /// The token stays borrowed for `'a`: it cannot be used mutably until `'a` ends.
+    pub const fn leak(self) -> &'a T {
+        unsafe { &*self.cell.inner.get() }
+    }
+    /// Constructs a shorter-lived guard over the same cell and token.
+    pub const fn reborrow(&self) -> TokenGuard<'_, T, Token> {
+        TokenGuard {
+            cell: self.cell,
+            token: self.token,
+        }
+    }
 }
 impl<'a, T: ?Sized, Token: TokenTrait> Deref for TokenGuard<'a, T, Token> {
     type Target = T;
@@ -161,14 +1126,64 @@ pub struct TokenGuardMut<'a, T: ?Sized, Token: TokenTrait> {
     token: &'a mut Token,
 }
 impl<'a, T: ?Sized, Token: TokenTrait> TokenGuardMut<'a, T, Token> {
+    /// Constructs a guard over `cell`, which is useful to implement [`TokenAccess`] for wrappers around [`TokenCell`].
+    ///
+    /// # Safety
+    /// `token` must have been successfully compared against the identifier `cell` was constructed with.
+    pub const unsafe fn from_parts(cell: &'a TokenCell<T, Token>, token: &'a mut Token) -> Self {
+        TokenGuardMut { cell, token }
+    }
     /// Reborrows the token immutably.
-    pub fn token(&self) -> &Token {
+    pub const fn token(&self) -> &Token {
         self.token
     }
+    /// The identifier the guarded cell was constructed with, as returned by [`TokenCell::identifier`].
+    pub const fn cell_identifier(&self) -> &Token::Identifier {
+        &self.cell.token_id
+    }
     /// Reborrows the token mutably.
-    pub fn token_mut(&mut self) -> &mut Token {
+    ///
+    /// The guard doesn't hold a reference to the cell's contents, only deriving one each time it's dereferenced,
+    /// and the token reborrow keeps the guard itself mutably borrowed: the token may even be used to borrow the same cell again, since no other borrow of its contents can be alive meanwhile.
+    /// ```rust
+    /// # use token_cell::{prelude::*, RuntimeToken};
+    /// let mut token = RuntimeToken::new().unwrap();
+    /// let cell = TokenCell::new(1, &token);
+    /// let mut guard = cell.try_guard_mut(&mut token).unwrap();
+    /// *guard += 1;
+    /// *cell.borrow_mut(guard.token_mut()) += 1;
+    /// *guard += 1;
+    /// assert_eq!(*guard, 4);
+    /// ```
+    ///
+    /// Keeping a borrow of the contents alive across the token reborrow is rejected at compile time.
+    /// ```compile_fail
+    /// # use token_cell::{prelude::*, RuntimeToken};
+    /// let mut token = RuntimeToken::new().unwrap();
+    /// let cell = TokenCell::new(1, &token);
+    /// let mut guard = cell.try_guard_mut(&mut token).unwrap();
+    /// let contents: &mut i32 = &mut guard;
+    /// let aliased = cell.borrow_mut(guard.token_mut());
+    /// *contents += *aliased;
+    /// ```
+    pub const fn token_mut(&mut self) -> &mut Token {
         self.token
     }
+    /// Consumes the guard, keeping only the mutable borrow of the cell's value, similarly to [`core::cell::RefMut::leak`].
+    ///
+    /// The token stays mutably borrowed for `'a`: it cannot be used to access any cell until `'a` ends.
+    pub fn leak(self) -> &'a mut T {
+        unsafe { &mut *self.cell.inner.get() }
+    }
+    /// Constructs a shorter-lived guard over the same cell and token.
+    ///
+    /// `self` stays mutably borrowed for as long as the new guard lives, so the two can never be used concurrently.
+    pub const fn reborrow(&mut self) -> TokenGuardMut<'_, T, Token> {
+        TokenGuardMut {
+            cell: self.cell,
+            token: self.token,
+        }
+    }
 }
 impl<'a, T: ?Sized, Token: TokenTrait> Deref for TokenGuardMut<'a, T, Token> {
     type Target = T;
@@ -176,36 +1191,562 @@ impl<'a, T: ?Sized, Token: TokenTrait> Deref for TokenGuardMut<'a, T, Token> {
         unsafe { &*self.cell.inner.get() }
     }
 }
-impl<'a, T, Token: TokenTrait> core::ops::DerefMut for TokenGuardMut<'a, T, Token> {
+impl<'a, T: ?Sized, Token: TokenTrait> core::ops::DerefMut for TokenGuardMut<'a, T, Token> {
     fn deref_mut(&mut self) -> &mut Self::Target {
         unsafe { &mut *self.cell.inner.get() }
     }
 }
+/// Guards can be passed to APIs expecting [`AsRef`] or [`Borrow`](core::borrow::Borrow).
+/// ```rust
+/// # use token_cell::prelude::*;
+/// # use std::borrow::BorrowMut;
+/// # token_cell::unsafe_token!(Token);
+/// fn push(mut values: impl BorrowMut<Vec<u32>>) {
+///     values.borrow_mut().push(1);
+/// }
+/// let mut token = Token::new().unwrap();
+/// let cell = TokenCell::new(Vec::new(), &token);
+/// push(cell.try_guard_mut(&mut token).unwrap());
+/// assert_eq!(cell.try_guard(&token).unwrap().as_ref(), &[1]);
+/// ```
+impl<'a, T: ?Sized, Token: TokenTrait> AsRef<T> for TokenGuard<'a, T, Token> {
+    fn as_ref(&self) -> &T {
+        self
+    }
+}
+impl<'a, T: ?Sized, Token: TokenTrait> core::borrow::Borrow<T> for TokenGuard<'a, T, Token> {
+    fn borrow(&self) -> &T {
+        self
+    }
+}
+impl<'a, T: ?Sized, Token: TokenTrait> AsRef<T> for TokenGuardMut<'a, T, Token> {
+    fn as_ref(&self) -> &T {
+        self
+    }
+}
+impl<'a, T: ?Sized, Token: TokenTrait> AsMut<T> for TokenGuardMut<'a, T, Token> {
+    fn as_mut(&mut self) -> &mut T {
+        self
+    }
+}
+impl<'a, T: ?Sized, Token: TokenTrait> core::borrow::Borrow<T> for TokenGuardMut<'a, T, Token> {
+    fn borrow(&self) -> &T {
+        self
+    }
+}
+impl<'a, T: ?Sized, Token: TokenTrait> core::borrow::BorrowMut<T> for TokenGuardMut<'a, T, Token> {
+    fn borrow_mut(&mut self) -> &mut T {
+        self
+    }
+}
+/// Guards format like their contents.
+/// ```rust
+/// # use token_cell::prelude::*;
+/// # token_cell::unsafe_token!(Token);
+/// let mut token = Token::new().unwrap();
+/// let cell = TokenCell::new(1, &token);
+/// assert_eq!(cell.try_guard(&token).unwrap().to_string(), "1");
+/// assert_eq!(format!("{:?}", cell.try_guard_mut(&mut token).unwrap()), "1");
+/// ```
+impl<'a, T: ?Sized + fmt::Display, Token: TokenTrait> fmt::Display for TokenGuard<'a, T, Token> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        (**self).fmt(f)
+    }
+}
+impl<'a, T: ?Sized + fmt::Debug, Token: TokenTrait> fmt::Debug for TokenGuard<'a, T, Token> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        (**self).fmt(f)
+    }
+}
+impl<'a, T: ?Sized + fmt::Display, Token: TokenTrait> fmt::Display for TokenGuardMut<'a, T, Token> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        (**self).fmt(f)
+    }
+}
+impl<'a, T: ?Sized + fmt::Debug, Token: TokenTrait> fmt::Debug for TokenGuardMut<'a, T, Token> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        (**self).fmt(f)
+    }
+}
 
 /// A Cell that shifts the management of access permissions to its inner value onto a `Token`.
+///
+/// Like with [`UnsafeCell`], references to cells coerce to references to cells of unsized types.
+/// ```rust
+/// # use token_cell::prelude::*;
+/// # token_cell::unsafe_token!(Token);
+/// let token = Token::new().unwrap();
+/// let array = TokenCell::new([1u8; 32], &token);
+/// let slice: &TokenCell<[u8], Token> = &array;
+/// assert_eq!(slice.borrow(&token).len(), 32);
+/// let value = TokenCell::new(1, &token);
+/// let display: &TokenCell<dyn core::fmt::Display, Token> = &value;
+/// assert_eq!(display.borrow(&token).to_string(), "1");
+/// ```
+///
+/// Cells are `#[repr(C)]`, with their identifier first: for tokens whose identifier is zero-sized, such as [`unsafe_token`](crate::unsafe_token)s
+/// and [`GhostToken`](crate::ghost::GhostToken)s, a cell has the same layout as its contents.
+/// ```rust
+/// # use token_cell::prelude::*;
+/// # token_cell::unsafe_token!(Token);
+/// const _: () = assert!(core::mem::size_of::<TokenCell<[u32; 3], Token>>() == core::mem::size_of::<[u32; 3]>());
+/// ```
+#[repr(C)]
 pub struct TokenCell<T: ?Sized, Token: TokenTrait> {
     token_id: Token::Identifier,
     inner: UnsafeCell<T>,
 }
+/// With the `nightly` feature, cells coerce by value if their contents do, like [`UnsafeCell`].
+/// ```rust
+/// # use token_cell::prelude::*;
+/// # token_cell::unsafe_token!(Token);
+/// let token = Token::new().unwrap();
+/// let cell: TokenCell<&[u8], Token> = TokenCell::new(&[1, 2, 3], &token);
+/// assert_eq!(cell.borrow(&token).len(), 3);
+/// ```
+#[cfg(feature = "nightly")]
+impl<T: core::ops::CoerceUnsized<U>, U, Token: TokenTrait>
+    core::ops::CoerceUnsized<TokenCell<U, Token>> for TokenCell<T, Token>
+{
+}
 impl<T: ?Sized, Token: TokenTrait> TokenCell<T, Token> {
+    fn contents(&self) -> Range<usize> {
+        contents(&self.inner)
+    }
+    /// Runs `f` on the inner data borrowed mutably, along with a [`TokenReborrow`] that can still borrow other cells immutably.
+    ///
+    /// This is the way to read other cells while this one is mutably borrowed, which [`TokenAccess::try_borrow_mut`] prevents by holding the token.
+    /// ```rust
+    /// # use token_cell::{prelude::*, RuntimeToken};
+    /// let mut token = RuntimeToken::new().unwrap();
+    /// let (total, item) = (TokenCell::new(0, &token), TokenCell::new(2, &token));
+    /// total
+    ///     .scope_mut(&mut token, |total, token| *total += *token.try_borrow(&item).unwrap())
+    ///     .unwrap();
+    /// assert_eq!(*total.borrow(&token), 2);
+    /// ```
+    ///
+    /// # Errors
+    /// If the token provides runtime checking and detects that `self` was constructed with another token.
+    pub fn scope_mut<R, F: FnOnce(&mut T, TokenReborrow<'_, Token>) -> R>(
+        &self,
+        token: &mut Token,
+        f: F,
+    ) -> Result<R, Token::ComparisonError> {
+        token.compare(&self.token_id)?;
+        let reborrow = TokenReborrow {
+            token,
+            excluded: self.contents(),
+        };
+        Ok(f(unsafe { &mut *self.inner.get() }, reborrow))
+    }
+    /// Repeatedly runs `f` on the inner data borrowed mutably, along with a [`TokenReborrowMut`] that can still borrow other cells, until `f` breaks.
+    ///
+    /// This suits worklist algorithms, where each step updates this cell while touching its siblings.
+    /// The token is only compared once, and `f` doesn't get the token itself, as it could otherwise borrow this cell a second time.
+    /// ```rust
+    /// # use token_cell::{prelude::*, RuntimeToken};
+    /// use core::ops::ControlFlow;
+    /// let mut token = RuntimeToken::new().unwrap();
+    /// let counts = [TokenCell::new(0, &token), TokenCell::new(0, &token)];
+    /// let worklist = TokenCell::new(vec![0, 1, 0, 0], &token);
+    /// let processed = worklist
+    ///     .with_mut_reentrant(&mut token, |worklist, mut token| match worklist.pop() {
+    ///         Some(i) => {
+    ///             *token.try_borrow_mut(&counts[i]).unwrap() += 1;
+    ///             ControlFlow::Continue(())
+    ///         }
+    ///         None => ControlFlow::Break("done"),
+    ///     })
+    ///     .unwrap();
+    /// assert_eq!(processed, "done");
+    /// assert_eq!((*counts[0].borrow(&token), *counts[1].borrow(&token)), (3, 1));
+    /// ```
+    ///
+    /// # Errors
+    /// If the token provides runtime checking and detects that `self` was constructed with another token.
+    pub fn with_mut_reentrant<
+        R,
+        F: FnMut(&mut T, TokenReborrowMut<'_, Token>) -> ControlFlow<R>,
+    >(
+        &self,
+        token: &mut Token,
+        mut f: F,
+    ) -> Result<R, Token::ComparisonError> {
+        token.compare(&self.token_id)?;
+        let excluded = self.contents();
+        loop {
+            let reborrow = TokenReborrowMut {
+                token: &mut *token,
+                excluded: excluded.clone(),
+            };
+            if let ControlFlow::Break(result) = f(unsafe { &mut *self.inner.get() }, reborrow) {
+                return Ok(result);
+            }
+        }
+    }
+    /// Borrows the inner data immutably through a [`ReadView`], which erases the token from its type.
+    ///
+    /// # Errors
+    /// If the token provides runtime checking and detects that `self` was constructed with another token.
+    pub fn read_view<'a>(
+        &'a self,
+        token: &'a Token,
+    ) -> Result<ReadView<'a, T>, Token::ComparisonError> {
+        token.compare(&self.token_id)?;
+        Ok(ReadView {
+            inner: unsafe { &*self.inner.get() },
+        })
+    }
+    /// The identifier of the token this cell was constructed with, which is useful to investigate mismatches.
+    /// ```rust
+    /// # use token_cell::{prelude::*, RuntimeToken};
+    /// let token = RuntimeToken::new().unwrap();
+    /// let cell = TokenCell::new(1, &token);
+    /// assert_eq!(*cell.identifier(), token.identifier());
+    /// assert_ne!(*cell.identifier(), RuntimeToken::new().unwrap().identifier());
+    /// ```
+    pub const fn identifier(&self) -> &Token::Identifier {
+        &self.token_id
+    }
     /// While cells are typically behind immutable references,
     /// obtaining a mutable reference to one is still proof of unique access.
-    pub fn get(&mut self) -> &T {
+    pub const fn get(&mut self) -> &T {
         self.inner.get_mut()
     }
     /// While cells are typically behind immutable references,
     /// obtaining a mutable reference to one is still proof of unique access.
-    pub fn get_mut(&mut self) -> &mut T {
+    pub const fn get_mut(&mut self) -> &mut T {
         self.inner.get_mut()
     }
+    /// Borrows the inner data mutably without a token, like dereferencing [`UnsafeCell::get`] would.
+    /// ```rust
+    /// # use token_cell::prelude::*;
+    /// # token_cell::unsafe_token!(Token);
+    /// let token = Token::new().unwrap();
+    /// let cell = TokenCell::new(1, &token);
+    /// *unsafe { cell.get_mut_unchecked() } += 1;
+    /// assert_eq!(*cell.borrow(&token), 2);
+    /// ```
+    ///
+    /// # Safety
+    /// The caller must guarantee that no other reference to the inner data exists for as long as the returned one is alive.
+    #[allow(clippy::mut_from_ref)]
+    pub unsafe fn get_mut_unchecked(&self) -> &mut T {
+        &mut *self.inner.get()
+    }
+    /// Borrows the inner data mutably without a token, projecting the cell's pin onto it.
+    /// ```rust
+    /// # use token_cell::prelude::*;
+    /// # token_cell::unsafe_token!(Token);
+    /// let token = Token::new().unwrap();
+    /// let cell = core::pin::pin!(TokenCell::new(async { 1 }, &token));
+    /// let future = unsafe { cell.get_pin_mut() };
+    /// ```
+    ///
+    /// # Safety
+    /// Same as [`TokenCell::try_borrow_pinned_mut`]: since [`TokenAccess::try_borrow_mut`] hands out `&mut T` through a shared reference to the cell, pinning the cell doesn't prevent its contents from being moved.
+    ///
+    /// Once this has been called, the caller must ensure that the contents are never moved out of the cell until they are dropped, unless `T: Unpin`.
+    pub unsafe fn get_pin_mut(self: Pin<&mut Self>) -> Pin<&mut T> {
+        self.map_unchecked_mut(|cell| cell.inner.get_mut())
+    }
+    /// Attempts to borrow the inner data mutably, projecting the cell's pin onto it.
+    ///
+    /// This allows keeping a future in a cell and polling it with the token.
+    /// ```rust
+    /// # use token_cell::prelude::*;
+    /// # use std::{future::Future, sync::Arc, task::{Context, Poll, Wake}};
+    /// # struct Noop;
+    /// # impl Wake for Noop { fn wake(self: Arc<Self>) {} }
+    /// # token_cell::unsafe_token!(Token);
+    /// # let waker = Arc::new(Noop).into();
+    /// # let mut cx = Context::from_waker(&waker);
+    /// let mut token = Token::new().unwrap();
+    /// let cell = core::pin::pin!(TokenCell::new(async { 1 }, &token));
+    /// let future = unsafe { cell.as_ref().try_borrow_pinned_mut(&mut token) }.unwrap();
+    /// assert_eq!(future.poll(&mut cx), Poll::Ready(1));
+    /// ```
+    ///
+    /// # Safety
+    /// The cell stores its contents inline and never moves them by itself, so pinning the cell would structurally pin its contents,
+    /// were it not for [`TokenAccess::try_borrow_mut`] handing out `&mut T` through a shared reference to the cell: pinning the cell doesn't prevent its contents from being moved that way.
+    ///
+    /// Once this has been called, the caller must ensure that the contents are never moved out of the cell until they are dropped, unless `T: Unpin`.
+    ///
+    /// # Errors
+    /// If the token provides runtime checking and detects that `self` was constructed with another token.
+    pub unsafe fn try_borrow_pinned_mut<'l>(
+        self: Pin<&'l Self>,
+        token: &'l mut Token,
+    ) -> Result<Pin<&'l mut T>, Token::ComparisonError> {
+        self.get_ref()
+            .try_borrow_mut(token)
+            .map_likely(|inner| Pin::new_unchecked(inner))
+    }
 }
 impl<T: Sized, Token: TokenTrait> TokenCell<T, Token> {
+    /// Constructs a new cell using `token` as its key, like [`TokenConstruct::new`], which this lets you call without importing it.
+    pub fn new(inner: T, token: &Token) -> Self {
+        Self::from_identifier(inner, token.identifier())
+    }
+    /// Constructs a cell keyed by the token whose identifier is `token_id`, such as one obtained from [`TokenCell::into_parts`].
+    ///
+    /// Storing an identifier grants no access to the cell, which still requires the matching token to be borrowed.
+    /// ```rust
+    /// # use token_cell::{prelude::*, RuntimeToken};
+    /// let token = RuntimeToken::new().unwrap();
+    /// let (value, id) = TokenCell::new(1, &token).into_parts();
+    /// let cell = TokenCell::<_, RuntimeToken>::from_identifier(value + 1, id);
+    /// assert_eq!(*cell.borrow(&token), 2);
+    /// ```
+    pub const fn from_identifier(inner: T, token_id: Token::Identifier) -> Self {
+        TokenCell {
+            token_id,
+            inner: UnsafeCell::new(inner),
+        }
+    }
+    /// Constructs a cell for each of `values`, all keyed by `token`, whose identifier is only obtained once.
+    /// ```rust
+    /// # use token_cell::{prelude::*, RuntimeToken};
+    /// let token = RuntimeToken::new().unwrap();
+    /// let cells = TokenCell::from_array([1, 2, 3], &token);
+    /// assert_eq!(*cells[2].borrow(&token), 3);
+    /// ```
+    pub fn from_array<const N: usize>(values: [T; N], token: &Token) -> [Self; N]
+    where
+        Token::Identifier: Clone,
+    {
+        let token_id = token.identifier();
+        values.map(|value| Self::from_identifier(value, token_id.clone()))
+    }
+    /// With the `alloc` feature, constructs a cell for each item of `iter`, all keyed by `token`, whose identifier is only obtained once.
+    /// ```rust
+    /// # use token_cell::{prelude::*, RuntimeToken};
+    /// let token = RuntimeToken::new().unwrap();
+    /// let cells = TokenCell::from_iter_with((0..10).map(|i| i * i), &token);
+    /// assert_eq!(*cells[9].borrow(&token), 81);
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn from_iter_with<I: IntoIterator<Item = T>>(
+        iter: I,
+        token: &Token,
+    ) -> alloc::vec::Vec<Self>
+    where
+        Token::Identifier: Clone,
+    {
+        let token_id = token.identifier();
+        iter.into_iter()
+            .map(|value| Self::from_identifier(value, token_id.clone()))
+            .collect()
+    }
     /// Unwraps the value from the cell.
     ///
     /// Full ownership of the cell is sufficient proof that the inner value can be recovered.
     pub fn into_inner(self) -> T {
         self.inner.into_inner()
     }
+    /// Unwraps the value from the cell, after checking that `token` is the one it was constructed with.
+    /// ```rust
+    /// # use token_cell::{prelude::*, RuntimeToken};
+    /// let token = RuntimeToken::new().unwrap();
+    /// let cell = TokenCell::new(1, &token);
+    /// let (cell, _) = cell.try_into_inner(&RuntimeToken::new().unwrap()).unwrap_err();
+    /// assert_eq!(cell.try_into_inner(&token).ok(), Some(1));
+    /// ```
+    ///
+    /// # Errors
+    /// If the token provides runtime checking and detects that `self` was constructed with another token, in which case the cell is returned.
+    pub fn try_into_inner(self, token: &Token) -> Result<T, (Self, Token::ComparisonError)> {
+        match token.compare(&self.token_id) {
+            Ok(()) => Ok(self.into_inner()),
+            Err(e) => Err((self, e)),
+        }
+    }
+    /// Unwraps the value from the cell, along with the identifier of the token it was constructed with.
+    ///
+    /// Full ownership of the cell is sufficient proof that the inner value can be recovered.
+    pub fn into_parts(self) -> (T, Token::Identifier) {
+        (self.inner.into_inner(), self.token_id)
+    }
+    /// Maps the value in the cell, keeping it keyed to the same token.
+    ///
+    /// Full ownership of the cell is sufficient proof that the inner value can be recovered, so no token is needed.
+    /// ```rust
+    /// # use token_cell::{prelude::*, RuntimeToken};
+    /// let token = RuntimeToken::new().unwrap();
+    /// let cell = TokenCell::new(1, &token).map_inner(|value| value.to_string());
+    /// assert_eq!(cell.borrow(&token), "1");
+    /// ```
+    ///
+    /// Deferred operations such as [`TokenAccess::map`] borrow the cell, so none of them may outlive it being consumed.
+    /// ```compile_fail
+    /// # use token_cell::{prelude::*, RuntimeToken};
+    /// let token = RuntimeToken::new().unwrap();
+    /// let cell = TokenCell::new(1, &token);
+    /// let stale = cell.map(|value| *value);
+    /// let cell = cell.map_inner(|value| value.to_string());
+    /// stale.try_apply(&token);
+    /// ```
+    pub fn map_inner<U, F: FnOnce(T) -> U>(self, f: F) -> TokenCell<U, Token> {
+        let (inner, token_id) = self.into_parts();
+        TokenCell::from_identifier(f(inner), token_id)
+    }
+    /// Rebinds the cell to `token`, which may be of another type, e.g. to migrate from [`unsafe_token`](crate::unsafe_token)s to [`runtime_token`](crate::runtime_token)s.
+    ///
+    /// Full ownership of the cell is sufficient proof that the inner value can be recovered, so the previous token isn't needed.
+    /// ```rust
+    /// # use token_cell::{prelude::*, RuntimeToken};
+    /// # token_cell::unsafe_token!(Unchecked);
+    /// let cell = TokenCell::new(1, &Unchecked::new().unwrap());
+    /// let token = RuntimeToken::new().unwrap();
+    /// let cell = cell.rekey(&token);
+    /// assert_eq!(*cell.borrow(&token), 1);
+    /// assert!(cell.try_borrow(&RuntimeToken::new().unwrap()).is_err());
+    /// ```
+    pub fn rekey<NewToken: TokenTrait>(self, token: &NewToken) -> TokenCell<T, NewToken> {
+        TokenCell::new(self.into_inner(), token)
+    }
+    /// Overwrites the inner data like [`Cell::set`](core::cell::Cell::set) would, using only a shared reference to the token.
+    /// ```rust
+    /// # use token_cell::{prelude::*, RuntimeToken};
+    /// let token = RuntimeToken::new().unwrap();
+    /// let cell = TokenCell::new(1, &token);
+    /// unsafe { cell.set_shared(2, &token) }.unwrap();
+    /// assert_eq!(*cell.borrow(&token), 2);
+    /// ```
+    ///
+    /// # Errors
+    /// If the token provides runtime checking and detects that `self` was constructed with another token.
+    ///
+    /// # Safety
+    /// Shared references to a token can still be used to borrow the inner data immutably, so nothing prevents such borrows from being alive during this call.
+    /// The caller must ensure that no reference to the inner data is alive, on this thread or any other.
+    pub unsafe fn set_shared(&self, value: T, token: &Token) -> Result<(), Token::ComparisonError>
+    where
+        T: Copy,
+    {
+        token.compare(&self.token_id)?;
+        self.inner.get().write(value);
+        Ok(())
+    }
+}
+impl<T, Token: TokenTrait<Identifier = ()>> TokenCell<T, Token> {
+    /// Constructs a cell at compile time, which is possible for tokens whose only identifier is their type, such as [`unsafe_token`](crate::unsafe_token)s and [`singleton_token`](crate::singleton_token)s.
+    /// ```rust
+    /// # use token_cell::prelude::*;
+    /// token_cell::singleton_token!(Token);
+    /// static CELL: TokenCell<u32, Token> = TokenCell::new_static(1);
+    /// let token = Token::new().unwrap();
+    /// assert_eq!(*CELL.borrow(&token), 1);
+    /// ```
+    pub const fn new_static(inner: T) -> Self {
+        Self::from_identifier(inner, ())
+    }
+    /// Constructs a cell in a caller-provided slot, such as one from an arena or a fixed buffer, without allocating.
+    ///
+    /// This relies on the cell having the same layout as `T`, which is only the case for tokens whose only identifier is their type.
+    /// As with [`MaybeUninit::write`], the contents won't be dropped along with the slot.
+    /// ```rust
+    /// # use token_cell::prelude::*;
+    /// # use core::mem::MaybeUninit;
+    /// # token_cell::unsafe_token!(Token);
+    /// let mut token = Token::new().unwrap();
+    /// let mut slot = MaybeUninit::uninit();
+    /// let cell = TokenCell::emplace(&mut slot, [0u8; 1024], &token);
+    /// cell.borrow_mut(&mut token)[0] = 1;
+    /// assert_eq!(cell.borrow(&token)[0], 1);
+    /// ```
+    pub fn emplace<'buf>(
+        slot: &'buf mut MaybeUninit<T>,
+        inner: T,
+        _token: &Token,
+    ) -> &'buf mut Self {
+        Self::from_mut(slot.write(inner))
+    }
+}
+impl<T: ?Sized, Token: TokenTrait<Identifier = ()>> TokenCell<T, Token> {
+    /// Views a mutable reference as a cell, which is possible for tokens whose only identifier is their type since the cell then has the same layout as `T`.
+    /// ```rust
+    /// # use token_cell::prelude::*;
+    /// # token_cell::unsafe_token!(Token);
+    /// let token = Token::new().unwrap();
+    /// let mut values = [1, 2, 3];
+    /// let cells = TokenCell::<[i32], Token>::from_mut(&mut values).as_slice_of_cells();
+    /// assert_eq!(*cells[2].borrow(&token), 3);
+    /// ```
+    pub fn from_mut(inner: &mut T) -> &mut Self {
+        unsafe { &mut *(inner as *mut T as *mut Self) }
+    }
+}
+impl<T, Token: TokenTrait<Identifier = ()>> TokenCell<[T], Token> {
+    /// Views a cell of a slice as a slice of cells, which is possible for tokens whose only identifier is their type since cells then have the same layout as their contents.
+    pub const fn as_slice_of_cells(&self) -> &[TokenCell<T, Token>] {
+        unsafe { &*(self as *const Self as *const [TokenCell<T, Token>]) }
+    }
+}
+impl<T, Token: TokenTrait> TokenCell<MaybeUninit<T>, Token> {
+    /// Initializes the cell's contents with `value`, without dropping any previous value, like [`MaybeUninit::write`].
+    /// ```rust
+    /// # use token_cell::prelude::*;
+    /// # use core::mem::MaybeUninit;
+    /// # token_cell::unsafe_token!(Token);
+    /// let mut token = Token::new().unwrap();
+    /// let slot = TokenCell::new(MaybeUninit::uninit(), &token);
+    /// slot.write(1, &mut token).unwrap();
+    /// assert_eq!(*unsafe { slot.assume_init_ref(&token) }.unwrap(), 1);
+    /// ```
+    ///
+    /// # Errors
+    /// If the token provides runtime checking and detects that `self` was constructed with another token.
+    pub fn write<'l>(
+        &'l self,
+        value: T,
+        token: &'l mut Token,
+    ) -> Result<&'l mut T, Token::ComparisonError> {
+        self.try_borrow_mut(token)
+            .map_likely(move |slot| slot.write(value))
+    }
+    /// Borrows the cell's contents, assuming they have been initialized.
+    ///
+    /// # Safety
+    /// The contents must have been initialized, for example through [`TokenCell::write`], like for [`MaybeUninit::assume_init_ref`].
+    ///
+    /// # Errors
+    /// If the token provides runtime checking and detects that `self` was constructed with another token.
+    pub unsafe fn assume_init_ref<'l>(
+        &'l self,
+        token: &'l Token,
+    ) -> Result<&'l T, Token::ComparisonError> {
+        self.try_borrow(token)
+            .map_likely(|slot| slot.assume_init_ref())
+    }
+}
+impl<T, Token: TokenTrait> TokenCell<[T], Token> {
+    /// Borrows the inner slice mutably, split into two disjoint halves at `mid`.
+    /// ```rust
+    /// # use token_cell::prelude::*;
+    /// # token_cell::unsafe_token!(Token);
+    /// let mut token = Token::new().unwrap();
+    /// let cell: &TokenCell<[u8], _> = &TokenCell::new([1, 2, 3, 4], &token);
+    /// let (left, right) = cell.split_at_mut(1, &mut token).unwrap();
+    /// left[0] = right[2];
+    /// assert_eq!(cell.borrow(&token), &[4, 2, 3, 4]);
+    /// ```
+    ///
+    /// # Errors
+    /// If the token provides runtime checking and detects that `self` was constructed with another token.
+    ///
+    /// # Panics
+    /// If `mid > len`, after the token has been checked.
+    pub fn split_at_mut<'l>(
+        &'l self,
+        mid: usize,
+        token: &'l mut Token,
+    ) -> Result<(&'l mut [T], &'l mut [T]), Token::ComparisonError> {
+        self.try_borrow_mut(token)
+            .map_likely(move |slice| slice.split_at_mut(mid))
+    }
 }
 impl<T: ?Sized, Token: TokenTrait> Deref for TokenCell<T, Token> {
     type Target = UnsafeCell<T>;
@@ -221,7 +1762,37 @@ impl<T: ?Sized, Token: TokenTrait> DerefMut for TokenCell<T, Token> {
 
 unsafe impl<T: ?Sized, Token: TokenTrait> Sync for TokenCell<T, Token> {}
 
-impl<T: ?Sized, Token: TokenTrait> TokenCellTrait<T, Token> for TokenCell<T, Token> {
+/// Formatting a cell with `{:p}` prints the address of its contents, which requires no token.
+/// ```rust
+/// # use token_cell::prelude::*;
+/// # token_cell::unsafe_token!(Token);
+/// let token = Token::new().unwrap();
+/// let cell = TokenCell::new(1, &token);
+/// assert_eq!(format!("{:p}", cell), format!("{:p}", cell.borrow(&token)));
+/// ```
+impl<T: ?Sized, Token: TokenTrait> fmt::Pointer for TokenCell<T, Token> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Pointer::fmt(&self.inner.get(), f)
+    }
+}
+
+/// With the `bytemuck` feature, cells are [`Zeroable`](bytemuck::Zeroable) if both their contents and their token's identifier are.
+///
+/// A zeroed cell is keyed to the zeroed identifier, which is the only one for ZST identifiers such as those of [`unsafe_token`](crate::unsafe_token)s.
+/// ```rust
+/// # use token_cell::prelude::*;
+/// # token_cell::unsafe_token!(Token);
+/// let token = Token::new().unwrap();
+/// let cells: [TokenCell<u32, Token>; 64] = bytemuck::Zeroable::zeroed();
+/// assert_eq!(*cells[63].borrow(&token), 0);
+/// ```
+#[cfg(feature = "bytemuck")]
+unsafe impl<T: bytemuck::Zeroable, Token: TokenTrait> bytemuck::Zeroable for TokenCell<T, Token> where
+    Token::Identifier: bytemuck::Zeroable
+{
+}
+
+impl<T: ?Sized, Token: TokenTrait> TokenConstruct<T, Token> for TokenCell<T, Token> {
     fn new(inner: T, token: &Token) -> Self
     where
         T: Sized,
@@ -231,6 +1802,8 @@ impl<T: ?Sized, Token: TokenTrait> TokenCellTrait<T, Token> for TokenCell<T, Tok
             token_id: token.identifier(),
         }
     }
+}
+impl<T: ?Sized, Token: TokenTrait> TokenAccess<T, Token> for TokenCell<T, Token> {
     fn try_guard<'l>(
         &'l self,
         token: &'l Token,
@@ -262,3 +1835,28 @@ impl<T: ?Sized, Token: TokenTrait> TokenCellTrait<T, Token> for TokenCell<T, Tok
             .map_likely(move |_| unsafe { &mut *self.inner.get() })
     }
 }
+impl<T: ?Sized, Token: TokenTrait, P: Deref<Target = TokenCell<T, Token>>> TokenAccess<T, Token>
+    for P
+{
+    fn try_guard<'l>(
+        &'l self,
+        token: &'l Token,
+    ) -> Result<TokenGuard<'l, T, Token>, <Token as TokenTrait>::ComparisonError> {
+        (**self).try_guard(token)
+    }
+    fn try_borrow<'l>(&'l self, token: &'l Token) -> Result<&'l T, Token::ComparisonError> {
+        (**self).try_borrow(token)
+    }
+    fn try_guard_mut<'l>(
+        &'l self,
+        token: &'l mut Token,
+    ) -> Result<TokenGuardMut<'l, T, Token>, <Token as TokenTrait>::ComparisonError> {
+        (**self).try_guard_mut(token)
+    }
+    fn try_borrow_mut<'l>(
+        &'l self,
+        token: &'l mut Token,
+    ) -> Result<&'l mut T, Token::ComparisonError> {
+        (**self).try_borrow_mut(token)
+    }
+}
@@ -0,0 +1,35 @@
+pub use token_cell_derive::Token;
+
+/// The only field of a `#[derive(Token)]` struct, which ensures that the token can only be constructed through [`TokenTrait::new`](crate::core::TokenTrait::new).
+///
+/// `I` must be `u16` for `#[token(runtime)]` tokens, `()` for `#[token(singleton)]` and `#[token(unsafe)]` tokens, and [`AutoId`] for `#[token(auto)]` tokens.
+pub struct TokenId<I = ()>(I);
+impl<I> TokenId<I> {
+    #[doc(hidden)]
+    pub const unsafe fn new_unchecked(id: I) -> Self {
+        TokenId(id)
+    }
+    /// Returns the token's identifier.
+    pub const fn get(&self) -> &I {
+        &self.0
+    }
+}
+
+/// The identifier of `#[token(auto)]` tokens, which are runtime-checked under the same conditions as [`token`](crate::token).
+#[cfg(any(
+    feature = "force-checked",
+    all(
+        not(feature = "force-unsafe"),
+        any(feature = "debug", debug_assertions)
+    )
+))]
+pub type AutoId = u16;
+/// The identifier of `#[token(auto)]` tokens, which are runtime-checked under the same conditions as [`token`](crate::token).
+#[cfg(not(any(
+    feature = "force-checked",
+    all(
+        not(feature = "force-unsafe"),
+        any(feature = "debug", debug_assertions)
+    )
+)))]
+pub type AutoId = ();
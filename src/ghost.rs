@@ -1,15 +1,39 @@
 use core::{cell::UnsafeCell, convert::Infallible};
 
-use crate::core::TokenTrait;
+use crate::core::{TokenCell, TokenTrait};
 
 /// The identifier for a [`GhostToken`]-based cell is its [`InvariantLifetime`]
+///
+/// It is invariant in `'a`, so that a brand can never be converted into another one, which branded tokens rely on for soundness.
+/// Custom branded tokens may embed one to inherit that invariance.
+/// ```compile_fail
+/// # use token_cell::ghost::InvariantLifetime;
+/// fn shorten<'long: 'short, 'short>(brand: InvariantLifetime<'long>) -> InvariantLifetime<'short> {
+///     brand
+/// }
+/// ```
+/// ```compile_fail
+/// # use token_cell::ghost::InvariantLifetime;
+/// fn lengthen<'long: 'short, 'short>(brand: InvariantLifetime<'short>) -> InvariantLifetime<'long> {
+///     brand
+/// }
+/// ```
 #[derive(Clone, Copy)]
 pub struct InvariantLifetime<'a>(core::marker::PhantomData<UnsafeCell<&'a ()>>);
 impl<'a> InvariantLifetime<'a> {
-    const fn new() -> Self {
+    /// Constructs the marker for the brand `'a`.
+    ///
+    /// Owning a marker grants nothing: tokens built upon it must still ensure that each brand is only ever handed to a single token,
+    /// typically by only producing brands through a `for<'brand>` closure like [`GhostToken::with_token`] does.
+    pub const fn new() -> Self {
         Self(core::marker::PhantomData)
     }
 }
+impl<'a> Default for InvariantLifetime<'a> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
 
 /// A lifetime based token, inspired by `ghost_cell`.
 ///
@@ -57,14 +81,28 @@ impl<'a> InvariantLifetime<'a> {
 /// .unwrap();
 /// ```
 pub struct GhostToken<'brand>(InvariantLifetime<'brand>);
+/// The construction error for [`GhostToken`]s, which can only be constructed through [`TokenTrait::with_token`].
+/// ```rust
+/// # use token_cell::{prelude::*, ghost::GhostToken};
+/// let error = GhostToken::new().err().unwrap();
+/// assert_eq!(error.to_string(), "GhostTokens can only be constructed through `TokenTrait::with_token`");
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct GhostTokenMustUseWithToken;
+impl core::fmt::Display for GhostTokenMustUseWithToken {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str("GhostTokens can only be constructed through `TokenTrait::with_token`")
+    }
+}
+
 impl<'brand> TokenTrait for GhostToken<'brand> {
-    type ConstructionError = ();
+    type ConstructionError = GhostTokenMustUseWithToken;
     type RunError = Infallible;
     type Identifier = InvariantLifetime<'brand>;
     type ComparisonError = Infallible;
     type Branded<'a> = GhostToken<'a>;
     fn new() -> Result<Self, Self::ConstructionError> {
-        Err(())
+        Err(GhostTokenMustUseWithToken)
     }
     /// ```rust
     /// use token_cell::{ghost::*, prelude::*};
@@ -106,3 +144,163 @@ impl<'brand> TokenTrait for GhostToken<'brand> {
         Ok(())
     }
 }
+
+impl<'brand> GhostToken<'brand> {
+    /// Borrows each of `cells` mutably at once.
+    /// ```rust
+    /// # use token_cell::{prelude::*, ghost::GhostToken};
+    /// GhostToken::with_token(|mut token| {
+    ///     let (a, b) = (TokenCell::new(1, &token), TokenCell::new(2, &token));
+    ///     let [a, b] = token.split_mut([&a, &b]);
+    ///     core::mem::swap(a, b);
+    ///     assert_eq!(*a, 2);
+    /// })
+    /// .unwrap();
+    /// ```
+    ///
+    /// # Panics
+    /// If any two of `cells` are at the same address, such as when they are the same cell.
+    /// ```should_panic
+    /// # use token_cell::{prelude::*, ghost::GhostToken};
+    /// GhostToken::with_token(|mut outer| {
+    ///     GhostToken::with_token(|inner| {
+    ///         let holder = TokenCell::new(inner, &outer);
+    ///         let _ = outer.split_mut([&holder, &holder]);
+    ///     })
+    ///     .unwrap();
+    /// })
+    /// .unwrap();
+    /// ```
+    pub fn split_mut<'a, T, const N: usize>(
+        &'a mut self,
+        cells: [&'a GhostCell<'brand, T>; N],
+    ) -> [&'a mut T; N] {
+        for (i, cell) in cells.iter().enumerate() {
+            assert!(
+                !cells[..i].iter().any(|other| core::ptr::eq(*other, *cell)),
+                "split_mut called with the same cell twice"
+            );
+        }
+        cells.map(|cell| unsafe { &mut *cell.as_ptr() })
+    }
+}
+
+/// Both [`GhostToken`] and [`ghost_cell::GhostToken`] are zero-sized and branded by an invariant lifetime that can only be obtained through a `for<'brand>` closure.
+///
+/// A given `'brand` is therefore only ever handed to a single token of either kind, which makes viewing one as the other sound.
+#[cfg(feature = "ghost-cell")]
+impl<'brand> GhostToken<'brand> {
+    /// Views this token as a [`ghost_cell::GhostToken`] of the same brand, allowing it to unlock [`ghost_cell::GhostCell`]s.
+    /// ```rust
+    /// # use token_cell::{prelude::*, ghost::GhostToken};
+    /// GhostToken::with_token(|mut token| {
+    ///     let cell = ghost_cell::GhostCell::new(1);
+    ///     *cell.borrow_mut(token.as_ghost_cell_token_mut()) += 1;
+    ///     assert_eq!(*cell.borrow(token.as_ghost_cell_token()), 2);
+    /// })
+    /// .unwrap();
+    /// ```
+    pub const fn as_ghost_cell_token(&self) -> &ghost_cell::GhostToken<'brand> {
+        unsafe { &*(self as *const Self).cast() }
+    }
+    /// Views this token as a mutable [`ghost_cell::GhostToken`] of the same brand, allowing it to mutably unlock [`ghost_cell::GhostCell`]s.
+    pub fn as_ghost_cell_token_mut(&mut self) -> &mut ghost_cell::GhostToken<'brand> {
+        unsafe { &mut *(self as *mut Self).cast() }
+    }
+    /// Views a [`ghost_cell::GhostToken`] as a [`GhostToken`] of the same brand, allowing it to unlock [`TokenCell`]s.
+    /// ```rust
+    /// # use token_cell::{prelude::*, ghost::GhostToken};
+    /// ghost_cell::GhostToken::new(|mut token| {
+    ///     let cell = TokenCell::new(1, GhostToken::from_ghost_cell_token(&token));
+    ///     *cell.borrow_mut(GhostToken::from_ghost_cell_token_mut(&mut token)) += 1;
+    ///     assert_eq!(*cell.borrow(GhostToken::from_ghost_cell_token(&token)), 2);
+    /// });
+    /// ```
+    pub const fn from_ghost_cell_token<'a>(token: &'a ghost_cell::GhostToken<'brand>) -> &'a Self {
+        unsafe { &*(token as *const ghost_cell::GhostToken<'brand>).cast() }
+    }
+    /// Views a mutable [`ghost_cell::GhostToken`] as a [`GhostToken`] of the same brand, allowing it to mutably unlock [`TokenCell`]s.
+    pub fn from_ghost_cell_token_mut<'a>(
+        token: &'a mut ghost_cell::GhostToken<'brand>,
+    ) -> &'a mut Self {
+        unsafe { &mut *(token as *mut ghost_cell::GhostToken<'brand>).cast() }
+    }
+}
+#[cfg(feature = "ghost-cell")]
+const _: () = assert!(
+    core::mem::size_of::<GhostToken<'static>>() == 0
+        && core::mem::size_of::<ghost_cell::GhostToken<'static>>() == 0
+        && core::mem::align_of::<ghost_cell::GhostToken<'static>>() == 1
+);
+
+/// A [`TokenCell`] keyed by a [`GhostToken`], named after [`ghost_cell::GhostCell`](https://docs.rs/ghost-cell) to ease porting.
+///
+/// Most of the upstream API translates one-to-one, the main exception being that [`TokenConstruct::new`](crate::core::TokenConstruct::new) takes the token as well.
+/// Since the brand alone identifies the cell, [`GhostCell::from`] is also available to construct one without a token.
+/// ```rust
+/// // use ghost_cell::{GhostToken, GhostCell};
+/// use token_cell::{prelude::*, ghost::{GhostToken, GhostCell}};
+///
+/// // GhostToken::new(|mut token| {
+/// GhostToken::with_token(|mut token| {
+///     // let cell = GhostCell::new(42);
+///     let cell = GhostCell::new(42, &token);
+///     let other = GhostCell::from(0);
+///     *cell.borrow_mut(&mut token) += 1;
+///     *other.borrow_mut(&mut token) = *cell.borrow(&token);
+///     assert_eq!(43, *other.borrow(&token));
+///     assert_eq!(43, cell.into_inner());
+/// // });
+/// })
+/// .unwrap();
+/// ```
+pub type GhostCell<'brand, T> = TokenCell<T, GhostToken<'brand>>;
+impl<'brand, T: ?Sized> GhostCell<'brand, T> {
+    /// Returns a raw pointer to the contained value.
+    pub fn as_ptr(&self) -> *mut T {
+        UnsafeCell::get(self)
+    }
+}
+macro_rules! assert_transparent {
+    ($($ty: ty),*) => {
+        $(const _: () = assert!(
+            core::mem::size_of::<GhostCell<'static, $ty>>() == core::mem::size_of::<$ty>()
+                && core::mem::align_of::<GhostCell<'static, $ty>>() == core::mem::align_of::<$ty>()
+        );)*
+    };
+}
+assert_transparent!((), u8, u64, [u16; 3], (u8, u32), &'static str);
+
+impl<'brand, T: ?Sized> GhostCell<'brand, T> {
+    /// Views a mutable reference as a cell, which is possible since the cell has the same layout as `T`.
+    /// ```rust
+    /// # use token_cell::{prelude::*, ghost::{GhostToken, GhostCell}};
+    /// GhostToken::with_token(|mut token| {
+    ///     let mut values = [1, 2, 3];
+    ///     let cells = GhostCell::<[i32]>::from_mut(&mut values).as_slice_of_cells();
+    ///     let [first, last] = token.split_mut([&cells[0], &cells[2]]);
+    ///     core::mem::swap(first, last);
+    ///     assert_eq!(*cells[0].borrow(&token), 3);
+    /// })
+    /// .unwrap();
+    /// ```
+    pub fn from_mut(inner: &mut T) -> &mut Self {
+        unsafe { &mut *(inner as *mut T as *mut Self) }
+    }
+}
+impl<'brand, T> GhostCell<'brand, [T]> {
+    /// Views a cell of a slice as a slice of cells, which is possible since cells have the same layout as their contents.
+    pub const fn as_slice_of_cells(&self) -> &[GhostCell<'brand, T>] {
+        unsafe { &*(self as *const Self as *const [GhostCell<'brand, T>]) }
+    }
+}
+impl<'brand, T> From<T> for GhostCell<'brand, T> {
+    fn from(value: T) -> Self {
+        Self::from_identifier(value, InvariantLifetime::new())
+    }
+}
+impl<'brand, T: Default> Default for GhostCell<'brand, T> {
+    fn default() -> Self {
+        Self::from(T::default())
+    }
+}
@@ -9,29 +9,119 @@
 //! This library provides an alternative to [`ghost-cell`](https://crates.io/crates/ghost-cell) which uses concrete types instead of lifetimes for branding.
 //!
 //! This allows a more convenient usage, where cells and tokens can be constructed independently, with the same compile-time guarantees as [`ghost-cell`](https://crates.io/crates/ghost-cell). The trade-off for this arguably more convenient usage and arguably easier to understand branding method is that tokens, while zero-sized if made correctly, must be guaranteed to be constructable only if no other instance exists.
-#![cfg_attr(not(features = "std"), no_std)]
+#![cfg_attr(not(feature = "std"), no_std)]
+#![cfg_attr(feature = "nightly", feature(coerce_unsized))]
 pub use paste::paste;
+#[cfg(feature = "alloc")]
+#[doc(hidden)]
+pub extern crate alloc;
 #[cfg(feature = "std")]
 mod std {
-    use crate::macros::{IdMismatch, SingletonUnavailable};
+    use crate::core::{TokenError, TokenTrait};
+    use crate::ghost::GhostTokenMustUseWithToken;
+    use crate::macros::{
+        AddressMismatch, CategoryInUse, IdMismatch, Poisoned, PoolExhausted, RevocableMismatch,
+        SingletonUnavailable, TokenExpired, TypeMismatch,
+    };
+    use crate::multi::DualMismatch;
     extern crate std;
     impl std::error::Error for IdMismatch {}
+    impl std::error::Error for AddressMismatch {}
+    impl std::error::Error for TokenExpired {}
+    impl std::error::Error for PoolExhausted {}
+    impl std::error::Error for Poisoned {}
+    impl std::error::Error for RevocableMismatch {}
     impl std::error::Error for SingletonUnavailable {}
+    impl std::error::Error for TypeMismatch {}
+    impl std::error::Error for CategoryInUse {}
+    impl std::error::Error for GhostTokenMustUseWithToken {}
+    impl<A: TokenTrait, B: TokenTrait> std::error::Error for DualMismatch<A, B>
+    where
+        A::ComparisonError: std::error::Error,
+        B::ComparisonError: std::error::Error,
+    {
+    }
+    impl<Token: TokenTrait> std::error::Error for TokenError<Token>
+    where
+        Token::ConstructionError: std::error::Error + 'static,
+        Token::RunError: std::error::Error + 'static,
+        Token::ComparisonError: std::error::Error + 'static,
+    {
+        fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+            match self {
+                TokenError::Construction(e) => e.source(),
+                TokenError::Run(e) => e.source(),
+                TokenError::Comparison(e) => e.source(),
+            }
+        }
+    }
 }
 /// The basis for using `token_cell`
 pub mod prelude {
-    pub use crate::core::{TokenCell, TokenCellTrait, TokenTrait};
+    pub use crate::core::{TokenAccess, TokenCell, TokenCellTrait, TokenConstruct, TokenTrait};
 }
 pub use crate::macros::token;
+/// Support for acquiring tokens from async contexts.
+#[cfg(feature = "async")]
+pub mod asynchronous;
 /// The core aspects of `token_cell`
 pub mod core;
+/// With the `derive` feature, `#[derive(Token)]` generates the same tokens as the [`macros`], for tuple structs whose only field is a [`TokenId`](derive::TokenId).
+///
+/// The kind of token is selected with `#[token(runtime)]` (the default), `#[token(singleton)]`, `#[token(unsafe)]`, or `#[token(auto)]`, which switches between runtime and unsafe tokens like [`token`].
+/// ```rust
+/// use token_cell::{prelude::*, derive::{Token, TokenId}};
+/// #[derive(Token)]
+/// struct Key(TokenId<u16>);
+/// #[derive(Token)]
+/// #[token(singleton)]
+/// struct Lock(TokenId);
+/// # fn main() {
+/// let key = Key::new().unwrap();
+/// let cell = TokenCell::new(1, &key);
+/// assert!(cell.try_borrow(&Key::new().unwrap()).is_err());
+/// let lock = Lock::new().unwrap();
+/// assert!(Lock::new().is_err());
+/// # }
+/// ```
+#[cfg(feature = "derive")]
+pub mod derive;
 /// A traitified version of `ghost_cell`.
 ///
 /// To use this, simply construct a [`TokenCell`](crate::prelude::TokenCell) using a [`GhostToken`](crate::ghost::GhostToken) obtained with the [`TokenTrait::with_token`](crate::prelude::TokenTrait::with_token) constructor.
 pub mod ghost;
 /// The macros to construct tokens.
+///
+/// Most tokens rely on atomics, some of which need compare-and-swap operations. On targets that lack them, such as `thumbv6m-none-eabi` and `riscv32imc-unknown-none-elf`,
+/// the `portable-atomic` feature makes tokens use [`portable-atomic`](https://docs.rs/portable-atomic)'s atomics instead.
+/// These targets also need either the `critical-section` feature, which implies it, along with a [`critical-section`](https://docs.rs/critical-section) implementation,
+/// or one of `portable-atomic`'s single-core options.
+///
+/// On single-core targets, `portable-atomic`'s `unsafe-assume-single-core` option implements these atomics by briefly disabling interrupts, without pulling in `critical-section`.
+/// Since interrupt handlers may construct tokens too, non-atomic counters wouldn't be sound there, which is why the crate doesn't provide any.
 pub mod macros;
 /// Because monads are cool.
 pub mod monads;
+/// Cells keyed by more than one token.
+pub mod multi;
+/// With the `std` feature, cells whose key is kept behind a [`Mutex`](::std::sync::Mutex), so that they may be shared between threads.
+#[cfg(feature = "std")]
+pub mod shared;
+/// The atomics used by the token macros, which are [`loom`](https://docs.rs/loom)'s when built with `--cfg loom` and the `loom` feature.
+#[doc(hidden)]
+pub mod atomics {
+    #[cfg(not(any(feature = "portable-atomic", all(feature = "loom", loom))))]
+    pub use core::sync::atomic::{AtomicBool, AtomicU16};
+    #[cfg(not(all(feature = "loom", loom)))]
+    pub use core::{hint::spin_loop, sync::atomic::Ordering};
+    #[cfg(all(feature = "loom", loom))]
+    pub use loom::{
+        self,
+        sync::atomic::{AtomicBool, AtomicU16, Ordering},
+        thread::yield_now as spin_loop,
+    };
+    #[cfg(all(feature = "portable-atomic", not(all(feature = "loom", loom))))]
+    pub use portable_atomic::{AtomicBool, AtomicU16};
+}
 
 runtime_token!(pub RuntimeToken);
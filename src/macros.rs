@@ -1,26 +1,216 @@
+use crate::atomics::Ordering;
+
+#[cfg(not(all(feature = "loom", loom)))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __atomic_static {
+    ($name: ident: [$ty: ty; $len: expr] = $init: expr) => {
+        static $name: [$ty; $len] = {
+            #[allow(clippy::declare_interior_mutable_const)]
+            const INIT: $ty = $init;
+            [INIT; $len]
+        };
+    };
+    ($name: ident: $ty: ty = $init: expr) => {
+        static $name: $ty = $init;
+    };
+}
+#[cfg(all(feature = "loom", loom))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __atomic_static {
+    ($name: ident: [$ty: ty; $len: expr] = $init: expr) => {
+        $crate::atomics::loom::lazy_static! {
+            static ref $name: [$ty; $len] = [(); $len].map(|()| $init);
+        }
+    };
+    ($name: ident: $ty: ty = $init: expr) => {
+        $crate::atomics::loom::lazy_static! {
+            static ref $name: $ty = $init;
+        }
+    };
+}
+
 /// Produces tokens that are also checked at runtime, ensuring that a [`TokenCell`](crate::core::TokenCell) is never accidentally used with another instance of the same token type.
+///
+/// Each token gets a new identifier, so construction fails with [`PoolExhausted`] once `u16::MAX` tokens have been constructed.
+/// [`reclaiming_runtime_token`](crate::reclaiming_runtime_token)s can be used instead if more tokens are needed over the program's lifetime.
+/// ```rust
+/// # use token_cell::prelude::*;
+/// token_cell::runtime_token!(Key);
+/// for _ in 0..u16::MAX {
+///     Key::new().unwrap();
+/// }
+/// assert!(Key::new().is_err());
+/// assert!(Key::with_token(|_| unreachable!()).is_err());
+/// ```
+///
+/// Cells keyed by one token type still can't be unlocked by another token type at all.
+/// ```compile_fail
+/// # use token_cell::prelude::*;
+/// token_cell::runtime_token!(Key, Other);
+/// let cell = TokenCell::new(1, &Key::new().unwrap());
+/// cell.try_borrow(&Other::new().unwrap());
+/// ```
+///
+/// The atomic [`Ordering`](core::sync::atomic::Ordering) used to hand out identifiers defaults to `Relaxed`, since only their uniqueness matters,
+/// and can be set with `runtime_token!(pub Key; ordering = AcqRel)` to also order construction against other memory operations.
+/// Loading the counter uses [`acquire_ordering`] of that ordering.
+/// ```rust
+/// # use token_cell::prelude::*;
+/// token_cell::runtime_token!(pub Key; ordering = AcqRel);
+/// # fn main() {
+/// let key = Key::new().unwrap();
+/// assert_eq!(*TokenCell::new(1, &key).borrow(&key), 1);
+/// # }
+/// ```
 #[macro_export]
 macro_rules! runtime_token {
 ($vis: vis $id: ident) => {
+    $crate::runtime_token!($vis $id; ordering = Relaxed);
+};
+($vis: vis $id: ident; ordering = $ordering: ident) => {
     $crate::paste! {
         $vis use [<__ $id _mod__ >]::$id;
         #[allow(nonstandard_style)]
         mod [<__ $id _mod__ >] {
+            #[allow(unused_imports)]
             use $crate::core::TokenCell;
-            use core::{convert::Infallible, sync::atomic::AtomicU16};
-            static COUNTER: AtomicU16 = AtomicU16::new(0);
+            use $crate::atomics::AtomicU16;
+            $crate::__atomic_static!(COUNTER: AtomicU16 = AtomicU16::new(0));
             /// A small token that's also checked at runtime, ensuring that a [`TokenCell`] is never accidentally used with another instance of the same token type.
             pub struct $id(u16);
+            impl $crate::core::TokenTrait for $id {
+                type ConstructionError = $crate::macros::PoolExhausted;
+                type RunError = Self::ConstructionError;
+                type Identifier = u16;
+                type ComparisonError = $crate::macros::IdMismatch;
+                type Branded<'a> = Self;
+                fn new() -> Result<Self, Self::ConstructionError> {
+                    // Wrapping around would let two live tokens share an identifier.
+                    COUNTER
+                        .fetch_update(
+                            $crate::atomics::Ordering::$ordering,
+                            $crate::macros::acquire_ordering($crate::atomics::Ordering::$ordering),
+                            |id| id.checked_add(1),
+                        )
+                        .map($id)
+                        .map_err(|_| $crate::macros::PoolExhausted)
+                }
+                fn with_token<R, F: FnOnce(Self)->R>(f: F) -> Result<R, Self::RunError> {
+                    Self::new().map(f)
+                }
+                fn identifier(&self) -> Self::Identifier {
+                    self.0
+                }
+                fn compare(&self, id: &Self::Identifier) -> Result<(), Self::ComparisonError> {
+                    if self.0 == *id {
+                        Ok(())
+                    } else {
+                        Err($crate::macros::IdMismatch {
+                            name: stringify!($id),
+                            cell: *id,
+                            token: self.0,
+                        }.reported())
+                    }
+                }
+            }
+        }
+    }
+};
+($($vis: vis $id: ident),*) => {
+    $($crate::runtime_token!($vis $id);)*
+}
+}
+
+/// Produces tokens that are checked at runtime using a custom [`TokenChecker`](crate::core::TokenChecker).
+#[macro_export]
+macro_rules! checked_token {
+($vis: vis $id: ident: $checker: ty) => {
+    $crate::paste! {
+        $vis use [<__ $id _mod__ >]::$id;
+        #[allow(nonstandard_style)]
+        mod [<__ $id _mod__ >] {
+            use super::*;
+            #[allow(unused_imports)]
+            use $crate::core::TokenCell;
+            use core::convert::Infallible;
+            use $crate::core::TokenChecker;
+            /// A token that's checked at runtime, ensuring that a [`TokenCell`] is never accidentally used with another instance of the same token type.
+            pub struct $id(<$checker as TokenChecker>::Identifier);
             impl $crate::core::TokenTrait for $id {
                 type ConstructionError = Infallible;
                 type RunError = Infallible;
+                type Identifier = <$checker as TokenChecker>::Identifier;
+                type ComparisonError = <$checker as TokenChecker>::Error;
+                type Branded<'a> = Self;
+                fn new() -> Result<Self, Self::ConstructionError> {
+                    Ok($id(<$checker as TokenChecker>::identify()))
+                }
+                fn with_token<R, F: FnOnce(Self)->R>(f: F) -> Result<R, Self::RunError> {
+                    Self::new().map(f)
+                }
+                fn identifier(&self) -> Self::Identifier {
+                    self.0.clone()
+                }
+                fn compare(&self, id: &Self::Identifier) -> Result<(), Self::ComparisonError> {
+                    <$checker as TokenChecker>::check(&self.0, id)
+                }
+            }
+        }
+    }
+};
+($($vis: vis $id: ident: $checker: ty),*) => {
+    $($crate::checked_token!($vis $id: $checker);)*
+}
+}
+
+/// Produces tokens that are checked at runtime like [`runtime_token`]s, but whose identifiers are reclaimed when they are dropped.
+///
+/// At most `capacity` tokens may be alive at any given time: construction fails with [`PoolExhausted`] beyond that.
+/// Since identifiers are reused, cells constructed with a dropped token become accessible to the next token that gets its identifier.
+/// ```rust
+/// # use token_cell::prelude::*;
+/// token_cell::reclaiming_runtime_token!(Key[2]);
+/// let a = Key::new().unwrap();
+/// let b = Key::new().unwrap();
+/// assert!(Key::new().is_err());
+/// let cell = TokenCell::new(1, &a);
+/// assert!(cell.try_borrow(&b).is_err());
+/// drop(a);
+/// let c = Key::new().unwrap();
+/// assert_eq!(*cell.borrow(&c), 1);
+/// ```
+#[macro_export]
+macro_rules! reclaiming_runtime_token {
+($vis: vis $id: ident [$capacity: expr]) => {
+    $crate::paste! {
+        $vis use [<__ $id _mod__ >]::$id;
+        #[allow(nonstandard_style)]
+        mod [<__ $id _mod__ >] {
+            #[allow(unused_imports)]
+            use $crate::core::TokenCell;
+            use core::convert::Infallible;
+            use $crate::{atomics::{AtomicBool, Ordering}, macros::PoolExhausted};
+            const _: () = assert!($capacity <= 1 << 16, "identifiers must fit in a u16");
+            $crate::__atomic_static!(IN_USE: [AtomicBool; $capacity] = AtomicBool::new(false));
+            /// A small token that's also checked at runtime, ensuring that a [`TokenCell`] is never accidentally used with another live instance of the same token type.
+            pub struct $id(u16);
+            impl $crate::core::TokenTrait for $id {
+                type ConstructionError = PoolExhausted;
+                type RunError = PoolExhausted;
                 type Identifier = u16;
                 type ComparisonError = $crate::macros::IdMismatch;
                 type Branded<'a> = Self;
                 fn new() -> Result<Self, Self::ConstructionError> {
-                    Ok($id(
-                        COUNTER.fetch_add(1, core::sync::atomic::Ordering::Relaxed),
-                    ))
+                    IN_USE
+                        .iter()
+                        .position(|slot| {
+                            slot.compare_exchange(false, true, Ordering::Acquire, Ordering::Relaxed)
+                                .is_ok()
+                        })
+                        .map(|id| $id(id as u16))
+                        .ok_or(PoolExhausted)
                 }
                 fn with_token<R, F: FnOnce(Self)->R>(f: F) -> Result<R, Self::RunError> {
                     Self::new().map(f)
@@ -33,8 +223,79 @@ macro_rules! runtime_token {
                         Ok(())
                     } else {
                         Err($crate::macros::IdMismatch {
+                            name: stringify!($id),
                             cell: *id,
                             token: self.0,
+                        }.reported())
+                    }
+                }
+            }
+            impl ::core::ops::Drop for $id {
+                fn drop(&mut self) {
+                    IN_USE[self.0 as usize].store(false, Ordering::Release);
+                }
+            }
+        }
+    }
+};
+($($vis: vis $id: ident [$capacity: expr]),*) => {
+    $($crate::reclaiming_runtime_token!($vis $id [$capacity]);)*
+}
+}
+
+/// Produces tokens that are checked at runtime like [`runtime_token`]s, but whose identifier is an [`Arc`](alloc::sync::Arc) rather than a counter.
+///
+/// A cell keeps its token's allocation alive, so two tokens can never share an identifier while a cell may still compare against it, no matter how many tokens get constructed.
+///
+/// The generated tokens are deliberately not [`Clone`]: two clones would both be able to mutably borrow the same cell. Share an `Arc<Mutex<Token>>` instead.
+/// ```rust
+/// # use token_cell::prelude::*;
+/// token_cell::arc_runtime_token!(Key);
+/// let mut key = Key::new().unwrap();
+/// let cell = TokenCell::new(1, &key);
+/// drop(key);
+/// let mut other = Key::new().unwrap();
+/// assert!(cell.try_borrow_mut(&mut other).is_err());
+/// ```
+#[cfg(feature = "alloc")]
+#[macro_export]
+macro_rules! arc_runtime_token {
+($vis: vis $id: ident) => {
+    $crate::paste! {
+        $vis use [<__ $id _mod__ >]::$id;
+        #[allow(nonstandard_style)]
+        mod [<__ $id _mod__ >] {
+            #[allow(unused_imports)]
+            use $crate::core::TokenCell;
+            use core::convert::Infallible;
+            use $crate::alloc::sync::Arc;
+            /// A token that's checked at runtime against the address of its [`Arc`] identifier, ensuring that a [`TokenCell`] is never accidentally used with another instance of the same token type.
+            pub struct $id(Arc<()>);
+            impl $crate::core::TokenTrait for $id {
+                type ConstructionError = Infallible;
+                type RunError = Infallible;
+                type Identifier = Arc<()>;
+                type ComparisonError = $crate::macros::AddressMismatch;
+                type Branded<'a> = Self;
+                fn new() -> Result<Self, Self::ConstructionError> {
+                    Ok($id(Arc::new(())))
+                }
+                fn with_token<R, F: FnOnce(Self)->R>(f: F) -> Result<R, Self::RunError> {
+                    Self::new().map(f)
+                }
+                fn identifier(&self) -> Self::Identifier {
+                    self.0.clone()
+                }
+                fn into_identifier(self) -> Self::Identifier {
+                    self.0
+                }
+                fn compare(&self, id: &Self::Identifier) -> Result<(), Self::ComparisonError> {
+                    if Arc::ptr_eq(&self.0, id) {
+                        Ok(())
+                    } else {
+                        Err($crate::macros::AddressMismatch {
+                            cell: Arc::as_ptr(id) as usize,
+                            token: Arc::as_ptr(&self.0) as usize,
                         })
                     }
                 }
@@ -43,34 +304,267 @@ macro_rules! runtime_token {
     }
 };
 ($($vis: vis $id: ident),*) => {
-    $($crate::runtime_token!($vis $id);)*
+    $($crate::arc_runtime_token!($vis $id);)*
+}
+}
+
+/// Produces tokens that revoke access to their cells when dropped.
+///
+/// Each token shares a liveness flag with the cells it constructs: once the token is dropped, any attempt at accessing these cells fails with [`RevocableMismatch::Expired`], even with another instance of the same token type.
+/// ```rust
+/// # use token_cell::{prelude::*, macros::RevocableMismatch};
+/// token_cell::revocable_token!(Plugin);
+/// let token = Plugin::new().unwrap();
+/// let cell = TokenCell::new(1, &token);
+/// assert_eq!(*cell.borrow(&token), 1);
+/// drop(token);
+/// let other = Plugin::new().unwrap();
+/// assert!(matches!(cell.try_borrow(&other), Err(RevocableMismatch::Expired(_))));
+/// ```
+#[cfg(feature = "alloc")]
+#[macro_export]
+macro_rules! revocable_token {
+($vis: vis $id: ident) => {
+    $crate::paste! {
+        $vis use [<__ $id _mod__ >]::$id;
+        #[allow(nonstandard_style)]
+        mod [<__ $id _mod__ >] {
+            #[allow(unused_imports)]
+            use $crate::core::TokenCell;
+            use core::convert::Infallible;
+            use $crate::{alloc::sync::Arc, atomics::{AtomicBool, Ordering}, macros::{AddressMismatch, RevocableMismatch, TokenExpired}};
+            /// A token that's checked at runtime, and revokes access to the [`TokenCell`]s it constructed when dropped.
+            pub struct $id(Arc<AtomicBool>);
+            impl $crate::core::TokenTrait for $id {
+                type ConstructionError = Infallible;
+                type RunError = Infallible;
+                type Identifier = Arc<AtomicBool>;
+                type ComparisonError = RevocableMismatch;
+                type Branded<'a> = Self;
+                fn new() -> Result<Self, Self::ConstructionError> {
+                    Ok($id(Arc::new(AtomicBool::new(true))))
+                }
+                fn with_token<R, F: FnOnce(Self)->R>(f: F) -> Result<R, Self::RunError> {
+                    Self::new().map(f)
+                }
+                fn identifier(&self) -> Self::Identifier {
+                    self.0.clone()
+                }
+                fn compare(&self, id: &Self::Identifier) -> Result<(), Self::ComparisonError> {
+                    if !id.load(Ordering::Acquire) {
+                        Err(RevocableMismatch::Expired(TokenExpired))
+                    } else if Arc::ptr_eq(&self.0, id) {
+                        Ok(())
+                    } else {
+                        Err(RevocableMismatch::Mismatch(AddressMismatch {
+                            cell: Arc::as_ptr(id) as usize,
+                            token: Arc::as_ptr(&self.0) as usize,
+                        }))
+                    }
+                }
+            }
+            impl ::core::ops::Drop for $id {
+                fn drop(&mut self) {
+                    self.0.store(false, Ordering::Release);
+                }
+            }
+        }
+    }
+};
+($($vis: vis $id: ident),*) => {
+    $($crate::revocable_token!($vis $id);)*
 }
 }
 
 /// Produces tokens whose only identifier is their type, but is built such that only one instance of it can exist at any given time.
 ///
 /// Looping on [`TokenTrait::new`](crate::core::TokenTrait::new) with a singleton token to access a [`TokenCell`](crate::core::TokenCell) is equivalent to using a spin-lock.
+///
+/// The atomic [`Ordering`](core::sync::atomic::Ordering) used to acquire and release the token defaults to `AcqRel`, so that the token behaves as a lock, and can be set to `SeqCst` with `singleton_token!(pub Token; ordering = SeqCst)`.
+/// Acquiring uses [`acquire_ordering`] of that ordering, and releasing uses [`release_ordering`] of it.
+/// ```rust
+/// # use token_cell::prelude::*;
+/// token_cell::singleton_token!(pub Flag; ordering = SeqCst);
+/// let flag = Flag::new().unwrap();
+/// assert!(Flag::new().is_err());
+/// drop(flag);
+/// assert!(Flag::new().is_ok());
+/// ```
+///
+/// Weaker orderings are rejected: without an acquire-release pair, a token's previous holder's writes to its cells wouldn't be visible to the next holder.
+/// ```compile_fail
+/// token_cell::singleton_token!(pub Flag; ordering = Relaxed);
+/// ```
+///
+/// If an instance is leaked, for example through [`core::mem::forget`], the token can be made available again with the generated `unsafe fn force_release()`.
+/// ```rust
+/// # use token_cell::prelude::*;
+/// token_cell::singleton_token!(pub Key);
+/// core::mem::forget(Key::new().unwrap());
+/// assert!(Key::new().is_err());
+/// unsafe { Key::force_release() };
+/// assert!(Key::new().is_ok());
+/// ```
+///
+/// `singleton_token!(pub Slot[4])` generates a bounded family of singleton tokens `Slot<0>` through `Slot<3>`, each of which can have one instance at any given time.
+/// ```rust
+/// # use token_cell::prelude::*;
+/// token_cell::singleton_token!(pub Slot[2]);
+/// let first = Slot::<0>::new().unwrap();
+/// let second = Slot::<1>::new().unwrap();
+/// assert!(Slot::<0>::new().is_err());
+/// let cell = TokenCell::new(1, &second);
+/// assert_eq!(*cell.borrow(&second), 1);
+/// ```
+/// ```compile_fail
+/// # use token_cell::prelude::*;
+/// token_cell::singleton_token!(pub Slot[2]);
+/// let third = Slot::<2>::new();
+/// ```
+///
+/// The flag tracking whether the token is available is a private static by default. `singleton_token!(pub Token in FLAG)` uses the `static FLAG: SingletonFlag` instead,
+/// which lets it be placed in a specific linker section.
+/// ```rust
+/// # use token_cell::{prelude::*, macros::SingletonFlag};
+/// #[link_section = ".data.tokens"]
+/// static FLAG: SingletonFlag = SingletonFlag::new();
+/// token_cell::singleton_token!(pub Key in FLAG);
+/// # fn main() {
+/// let key = Key::new().unwrap();
+/// assert!(Key::new().is_err());
+/// # }
+/// ```
+///
+/// With the `std` feature, `singleton_token!(pub Token; poison)` or `singleton_token!(pub Token; ordering = AcqRel, poison)` generates a token that's poisoned when an instance is dropped during a panic, like [`spin_token`](crate::spin_token)s.
+///
+/// With the `async` feature, the generated token also has an `acquire` associated function, which returns a future that resolves once the token could be constructed.
 #[macro_export]
 macro_rules! singleton_token {
-($vis: vis $id: ident) => {
+($vis: vis $id: ident $(in $flag: path)?) => {
+    $crate::singleton_token!($vis $id $(in $flag)?; ordering = AcqRel);
+};
+($vis: vis $id: ident $(in $flag: path)?; poison) => {
+    $crate::singleton_token!($vis $id $(in $flag)?; ordering = AcqRel, poison);
+};
+($vis: vis $id: ident $(in $flag: path)?; ordering = $ordering: ident) => {
+    $crate::singleton_token!(@$vis $id; $ordering; off; $($flag)?);
+};
+($vis: vis $id: ident $(in $flag: path)?; ordering = $ordering: ident, poison) => {
+    $crate::singleton_token!(@$vis $id; $ordering; poison; $($flag)?);
+};
+(@$vis: vis $id: ident; $ordering: ident; $poison: ident; $($flag: path)?) => {
+    $crate::__lock_ordering!($ordering);
     $crate::paste! {
         $vis use [<__ $id _mod__ >]::$id;
         #[allow(nonstandard_style)]
         mod [<__ $id _mod__ >] {
-            use core::{convert::Infallible, sync::atomic::AtomicBool};
-            use $crate::SingletonUnavailable;
-            static AVAILABLE: AtomicBool = AtomicBool::new(true);
+            use $crate::macros::SingletonUnavailable;
+            $crate::__singleton_flag!($($flag)?);
+            $crate::__singleton_async!($id);
+            $crate::__token_poison!($id; $poison);
             /// A ZST tokens whose only identifier is their type, but is built such that only one instance of it can exist at any given time.
             ///
             /// Looping on [`TokenTrait::new`](token_cell::core::TokenTrait::new) with this type to access a [`TokenCell`](token_cell::core::TokenCell) is equivalent to using a spin-lock.
             pub struct $id(());
             impl $crate::core::TokenTrait for $id {
+                type ConstructionError = SingletonUnavailable;
+                type RunError = SingletonUnavailable;
+                type Identifier = ();
+                type ComparisonError = PoisonError;
+                type Branded<'a> = Self;
+                fn new() -> Result<Self, Self::ConstructionError> {
+                    // Acquiring synchronizes with the release store of the previous instance's drop,
+                    // so that its accesses to the cells happen-before those of the new instance.
+                    if available()
+                        .compare_exchange(
+                            true,
+                            false,
+                            $crate::macros::acquire_ordering($crate::atomics::Ordering::$ordering),
+                            $crate::atomics::Ordering::Relaxed,
+                        )
+                        .is_ok()
+                    {
+                        Ok($id(()))
+                    } else {
+                        Err(SingletonUnavailable)
+                    }
+                }
+                fn with_token<R, F: FnOnce(Self)->R>(f: F) -> Result<R, Self::RunError> {
+                    Self::new().map(f)
+                }
+                fn identifier(&self) -> Self::Identifier {
+                    self.0
+                }
+                fn compare(&self, _: &Self::Identifier) -> Result<(), Self::ComparisonError> {
+                    check_poison()
+                }
+            }
+            impl $id {
+                /// Makes the token available again, even if its last instance was leaked instead of dropped.
+                ///
+                /// # Safety
+                /// No instance of this token may be alive, as it would no longer be the only one once another is constructed.
+                pub unsafe fn force_release() {
+                    available().store(true, $crate::macros::release_ordering($crate::atomics::Ordering::$ordering));
+                    notify();
+                }
+            }
+            impl ::core::ops::Drop for $id {
+                fn drop(&mut self) {
+                    poison_on_panic();
+                    available().store(true, $crate::macros::release_ordering($crate::atomics::Ordering::$ordering));
+                    notify();
+                }
+            }
+        }
+    }
+};
+($vis: vis $id: ident [$max: expr]) => {
+    $crate::singleton_token!($vis $id [$max]; ordering = AcqRel);
+};
+($vis: vis $id: ident [$max: expr]; ordering = $ordering: ident) => {
+    $crate::__lock_ordering!($ordering);
+    $crate::paste! {
+        $vis use [<__ $id _mod__ >]::$id;
+        #[allow(nonstandard_style)]
+        mod [<__ $id _mod__ >] {
+            use core::convert::Infallible;
+            use $crate::{atomics::AtomicBool, macros::SingletonUnavailable};
+            $crate::__atomic_static!(AVAILABLE: [AtomicBool; $max] = AtomicBool::new(true));
+            /// A family of ZST tokens whose only identifier is their type, but are built such that only one instance of each can exist at any given time.
+            ///
+            /// Looping on [`TokenTrait::new`](token_cell::core::TokenTrait::new) with this type to access a [`TokenCell`](token_cell::core::TokenCell) is equivalent to using a spin-lock.
+            pub struct $id<const N: usize>(());
+            impl<const N: usize> $id<N> {
+                const IN_BOUNDS: () = assert!(N < $max, concat!(stringify!($id), "'s index must be lower than ", stringify!($max)));
+                /// Makes the token available again, even if its last instance was leaked instead of dropped.
+                ///
+                /// # Safety
+                /// No instance of this token may be alive, as it would no longer be the only one once another is constructed.
+                pub unsafe fn force_release() {
+                    let () = Self::IN_BOUNDS;
+                    AVAILABLE[N].store(true, $crate::macros::release_ordering($crate::atomics::Ordering::$ordering));
+                }
+            }
+            impl<const N: usize> $crate::core::TokenTrait for $id<N> {
                 type ConstructionError = SingletonUnavailable;
                 type RunError = SingletonUnavailable;
                 type Identifier = ();
                 type ComparisonError = Infallible;
+                type Branded<'a> = Self;
                 fn new() -> Result<Self, Self::ConstructionError> {
-                    if AVAILABLE.swap(false, core::sync::atomic::Ordering::Relaxed) {
+                    let () = Self::IN_BOUNDS;
+                    // Acquiring synchronizes with the release store of the previous instance's drop,
+                    // so that its accesses to the cells happen-before those of the new instance.
+                    if AVAILABLE[N]
+                        .compare_exchange(
+                            true,
+                            false,
+                            $crate::macros::acquire_ordering($crate::atomics::Ordering::$ordering),
+                            $crate::atomics::Ordering::Relaxed,
+                        )
+                        .is_ok()
+                    {
                         Ok($id(()))
                     } else {
                         Err(SingletonUnavailable)
@@ -86,9 +580,9 @@ macro_rules! singleton_token {
                     Ok(())
                 }
             }
-            impl ::core::ops::Drop for $id {
+            impl<const N: usize> ::core::ops::Drop for $id<N> {
                 fn drop(&mut self) {
-                    AVAILABLE.store(true, core::sync::atomic::Ordering::Relaxed);
+                    AVAILABLE[N].store(true, $crate::macros::release_ordering($crate::atomics::Ordering::$ordering));
                 }
             }
         }
@@ -99,6 +593,223 @@ macro_rules! singleton_token {
 }
 }
 
+/// Rejects orderings passed to [`singleton_token`](crate::singleton_token) that are too weak for the token to act as a lock.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __lock_ordering {
+    (AcqRel) => {};
+    (SeqCst) => {};
+    ($other: ident) => {
+        compile_error!(concat!(
+            "singleton tokens must be acquired and released with `AcqRel` or `SeqCst` ordering, not `",
+            stringify!($other),
+            "`"
+        ));
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __singleton_flag {
+    () => {
+        $crate::__atomic_static!(AVAILABLE: $crate::atomics::AtomicBool = $crate::atomics::AtomicBool::new(true));
+        fn available() -> &'static $crate::atomics::AtomicBool {
+            &AVAILABLE
+        }
+    };
+    ($flag: path) => {
+        use super::*;
+        fn available() -> &'static $crate::atomics::AtomicBool {
+            let flag: &'static $crate::macros::SingletonFlag = &$flag;
+            unsafe { flag.as_atomic() }
+        }
+    };
+}
+
+#[cfg(feature = "async")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __singleton_async {
+    ($id: ident) => {
+        static WAITERS: $crate::asynchronous::WaitQueue = $crate::asynchronous::WaitQueue::new();
+        impl $id {
+            /// Waits for the token to become available without blocking the executor.
+            ///
+            /// Dropping the token wakes up one of the pending `acquire` futures.
+            pub fn acquire() -> impl ::core::future::Future<Output = Self> {
+                WAITERS.wait(|| <Self as $crate::core::TokenTrait>::new().ok())
+            }
+        }
+        fn notify() {
+            WAITERS.notify_one()
+        }
+    };
+}
+#[cfg(not(feature = "async"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __singleton_async {
+    ($id: ident) => {
+        const fn notify() {}
+    };
+}
+
+#[cfg(feature = "std")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __token_poison {
+    ($id: ident; off) => {
+        type PoisonError = ::core::convert::Infallible;
+        const fn check_poison() -> Result<(), PoisonError> {
+            Ok(())
+        }
+        const fn poison_on_panic() {}
+    };
+    ($id: ident; poison) => {
+        type PoisonError = $crate::macros::Poisoned;
+        $crate::__atomic_static!(POISONED: $crate::atomics::AtomicBool = $crate::atomics::AtomicBool::new(false));
+        // The poison is only set and read by the holder of the token, so the token's own synchronization orders it.
+        fn check_poison() -> Result<(), PoisonError> {
+            if POISONED.load($crate::atomics::Ordering::Relaxed) {
+                Err($crate::macros::Poisoned)
+            } else {
+                Ok(())
+            }
+        }
+        fn poison_on_panic() {
+            if $crate::macros::panicking() {
+                POISONED.store(true, $crate::atomics::Ordering::Relaxed);
+            }
+        }
+        impl $id {
+            /// Whether an instance of this token was dropped during a panic, in which case cells can't be accessed until [`Self::clear_poison`] is called.
+            pub fn is_poisoned() -> bool {
+                POISONED.load($crate::atomics::Ordering::Relaxed)
+            }
+            /// Lets cells be accessed again after an instance of this token was dropped during a panic.
+            pub fn clear_poison() {
+                POISONED.store(false, $crate::atomics::Ordering::Relaxed);
+            }
+        }
+    };
+}
+#[cfg(not(feature = "std"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __token_poison {
+    ($id: ident; off) => {
+        type PoisonError = ::core::convert::Infallible;
+        const fn check_poison() -> Result<(), PoisonError> {
+            Ok(())
+        }
+        const fn poison_on_panic() {}
+    };
+    ($id: ident; poison) => {
+        compile_error!("poisoning requires the std feature");
+    };
+}
+
+/// Produces tokens whose only identifier is their type, and whose construction spins until no other instance exists, making them spin-locks over all of their cells.
+///
+/// Unlike [`singleton_token`]s, construction never fails: it blocks instead.
+/// ```rust
+/// # use token_cell::prelude::*;
+/// # use std::sync::Arc;
+/// token_cell::spin_token!(Lock);
+/// let counter = Arc::new(TokenCell::new(0, &Lock::new().unwrap()));
+/// let handles: Vec<_> = (0..4)
+///     .map(|_| {
+///         let counter = counter.clone();
+///         std::thread::spawn(move || {
+///             let mut lock = Lock::new().unwrap();
+///             *counter.borrow_mut(&mut lock) += 1;
+///         })
+///     })
+///     .collect();
+/// for handle in handles {
+///     handle.join().unwrap();
+/// }
+/// assert_eq!(*counter.borrow(&Lock::new().unwrap()), 4);
+/// ```
+///
+/// With the `std` feature, `spin_token!(Lock; poison)` generates a token that's poisoned when an instance is dropped during a panic, like [`std::sync::Mutex`]:
+/// accessing any of its cells then fails with [`Poisoned`] until the generated `clear_poison` function is called.
+#[cfg_attr(feature = "std", doc = "```rust")]
+#[cfg_attr(not(feature = "std"), doc = "```ignore")]
+/// # use token_cell::{prelude::*, macros::Poisoned};
+/// token_cell::spin_token!(Lock; poison);
+/// let cell = TokenCell::new(0, &Lock::new().unwrap());
+/// let _ = std::panic::catch_unwind(|| {
+///     let _lock = Lock::new().unwrap();
+///     panic!()
+/// });
+/// assert!(Lock::is_poisoned());
+/// assert!(matches!(cell.try_borrow(&Lock::new().unwrap()), Err(Poisoned)));
+/// Lock::clear_poison();
+/// assert_eq!(*cell.borrow(&Lock::new().unwrap()), 0);
+/// ```
+#[macro_export]
+macro_rules! spin_token {
+($vis: vis $id: ident) => {
+    $crate::spin_token!(@$vis $id; off);
+};
+($vis: vis $id: ident; poison) => {
+    $crate::spin_token!(@$vis $id; poison);
+};
+(@$vis: vis $id: ident; $poison: ident) => {
+    $crate::paste! {
+        $vis use [<__ $id _mod__ >]::$id;
+        #[allow(nonstandard_style)]
+        mod [<__ $id _mod__ >] {
+            use core::convert::Infallible;
+            use $crate::atomics::{spin_loop, AtomicBool, Ordering};
+            $crate::__atomic_static!(LOCKED: AtomicBool = AtomicBool::new(false));
+            $crate::__token_poison!($id; $poison);
+            /// A ZST token whose only identifier is its type, and whose construction spins until no other instance exists.
+            pub struct $id(());
+            impl $crate::core::TokenTrait for $id {
+                type ConstructionError = Infallible;
+                type RunError = Infallible;
+                type Identifier = ();
+                type ComparisonError = PoisonError;
+                type Branded<'a> = Self;
+                fn new() -> Result<Self, Self::ConstructionError> {
+                    // Acquiring synchronizes with the release store of the previous instance's drop,
+                    // so that its accesses to the cells happen-before those of the new instance.
+                    while LOCKED
+                        .compare_exchange_weak(false, true, Ordering::Acquire, Ordering::Relaxed)
+                        .is_err()
+                    {
+                        while LOCKED.load(Ordering::Relaxed) {
+                            spin_loop();
+                        }
+                    }
+                    Ok($id(()))
+                }
+                fn with_token<R, F: FnOnce(Self)->R>(f: F) -> Result<R, Self::RunError> {
+                    Self::new().map(f)
+                }
+                fn identifier(&self) -> Self::Identifier {
+                    self.0
+                }
+                fn compare(&self, _: &Self::Identifier) -> Result<(), Self::ComparisonError> {
+                    check_poison()
+                }
+            }
+            impl ::core::ops::Drop for $id {
+                fn drop(&mut self) {
+                    poison_on_panic();
+                    LOCKED.store(false, Ordering::Release);
+                }
+            }
+        }
+    }
+};
+($($vis: vis $id: ident),*) => {
+    $($crate::spin_token!($vis $id);)*
+}
+}
+
 /// Produces tokens whose only identifier is their type.
 ///
 /// While unlikely, a potential misuse is constructing multiple instances of the same type and using one to access a cell constructed by another instance.
@@ -144,29 +855,678 @@ macro_rules! unsafe_token {
     $($crate::unsafe_token!($vis $id);)*
 }
 }
+/// With the `std` feature, produces tokens whose only instance lives behind a global [`Mutex`](std::sync::Mutex), the generated `key()`, which threads lock to access its cells.
+///
+/// Since that instance is the only one, [`TokenTrait::new`](crate::core::TokenTrait::new) always fails with [`SingletonUnavailable`], and borrowing cells with the locked token can't fail.
+/// The generated `share` function constructs a [`SharedCell`](crate::shared::SharedCell) keyed by it.
+/// ```rust
+/// # use token_cell::prelude::*;
+/// token_cell::mutex_token!(pub Lock);
+/// # fn main() {
+/// let counter = Lock::share(0);
+/// let handles: Vec<_> = (0..4)
+///     .map(|_| {
+///         let counter = counter.clone();
+///         std::thread::spawn(move || *counter.lock().unwrap() += 1)
+///     })
+///     .collect();
+/// for handle in handles {
+///     handle.join().unwrap();
+/// }
+/// assert!(Lock::new().is_err());
+/// assert_eq!(*counter.lock().unwrap(), 4);
+/// // Cells keyed by `Lock` can also be constructed by hand, while its key is held.
+/// let key = Lock::key();
+/// let mut token = key.lock().unwrap();
+/// let cell = TokenCell::new(1, &*token);
+/// *cell.borrow_mut(&mut token) += 1;
+/// assert_eq!(*cell.borrow(&token), 2);
+/// # }
+/// ```
+#[cfg(feature = "std")]
+#[macro_export]
+macro_rules! mutex_token {
+($vis: vis $id: ident) => {
+    $crate::paste! {
+        $vis use [<__ $id _mod__ >]::$id;
+        #[allow(nonstandard_style)]
+        mod [<__ $id _mod__ >] {
+            #[allow(unused_imports)]
+            use $crate::core::TokenCell;
+            use $crate::{alloc::sync::Arc, macros::SingletonUnavailable, shared::{OnceLock, SharedCell}};
+            use ::std::sync::Mutex;
+            /// A ZST token whose only instance is kept behind a global [`Mutex`], which must be locked to access [`TokenCell`]s keyed by it.
+            pub struct $id(());
+            impl $id {
+                /// The key holding this token's only instance.
+                pub fn key() -> Arc<Mutex<Self>> {
+                    static KEY: OnceLock<Arc<Mutex<$id>>> = OnceLock::new();
+                    KEY.get_or_init(|| Arc::new(Mutex::new($id(())))).clone()
+                }
+                /// Constructs a [`SharedCell`] unlocked by this token's key.
+                pub fn share<T>(inner: T) -> SharedCell<T, Self> {
+                    SharedCell::with_key(inner, Self::key())
+                }
+            }
+            impl $crate::core::TokenTrait for $id {
+                type ConstructionError = SingletonUnavailable;
+                type RunError = SingletonUnavailable;
+                type Identifier = ();
+                type ComparisonError = ::core::convert::Infallible;
+                type Branded<'a> = Self;
+                fn new() -> Result<Self, Self::ConstructionError> {
+                    Err(SingletonUnavailable)
+                }
+                fn with_token<R, F: FnOnce(Self)->R>(f: F) -> Result<R, Self::RunError> {
+                    Self::new().map(f)
+                }
+                fn identifier(&self) -> Self::Identifier {
+                    self.0
+                }
+                fn compare(&self, _: &Self::Identifier) -> Result<(), Self::ComparisonError> {
+                    Ok(())
+                }
+            }
+        }
+    }
+};
+($($vis: vis $id: ident),*) => {
+    $($crate::mutex_token!($vis $id);)*
+}
+}
+/// With the `std` feature, produces tokens whose identifier is the [`TypeId`](core::any::TypeId) of a marker type, so that a single token type may stand for several categories of cells.
+///
+/// Only one token per category may be alive at once, which is enforced through a global registry: constructing another one fails until the first one is dropped.
+/// [`TokenTrait::new`](crate::core::TokenTrait::new) constructs the token whose category is the token type itself.
+/// ```rust
+/// # use token_cell::prelude::*;
+/// token_cell::type_token!(Category);
+/// struct Physics;
+/// struct Render;
+/// let physics = Category::of::<Physics>().unwrap();
+/// assert!(Category::of::<Physics>().is_err());
+/// let cell = TokenCell::new(1, &physics);
+/// assert_eq!(*cell.borrow(&physics), 1);
+/// assert!(cell.try_borrow(&Category::of::<Render>().unwrap()).is_err());
+/// drop(physics);
+/// assert_eq!(*cell.borrow(&Category::of::<Physics>().unwrap()), 1);
+/// ```
+#[cfg(feature = "std")]
+#[macro_export]
+macro_rules! type_token {
+($vis: vis $id: ident) => {
+    $crate::paste! {
+        $vis use [<__ $id _mod__ >]::$id;
+        #[allow(nonstandard_style)]
+        mod [<__ $id _mod__ >] {
+            #[allow(unused_imports)]
+            use $crate::core::TokenCell;
+            use core::any::TypeId;
+            use $crate::macros::CategoryInUse;
+            /// A token checked at runtime against the category of the cell, only one of which may be alive per category at any given time.
+            pub struct $id(TypeId);
+            impl $id {
+                /// Constructs the token for the category `M`.
+                ///
+                /// # Errors
+                /// If a token of this type for `M` is still alive.
+                pub fn of<M: ?Sized + 'static>() -> Result<Self, CategoryInUse> {
+                    let category = TypeId::of::<M>();
+                    if $crate::macros::claim_category(TypeId::of::<Self>(), category) {
+                        Ok($id(category))
+                    } else {
+                        Err(CategoryInUse { name: stringify!($id) })
+                    }
+                }
+            }
+            impl ::core::ops::Drop for $id {
+                fn drop(&mut self) {
+                    $crate::macros::release_category(TypeId::of::<Self>(), self.0)
+                }
+            }
+            impl $crate::core::TokenTrait for $id {
+                type ConstructionError = CategoryInUse;
+                type RunError = CategoryInUse;
+                type Identifier = TypeId;
+                type ComparisonError = $crate::macros::TypeMismatch;
+                type Branded<'a> = Self;
+                fn new() -> Result<Self, Self::ConstructionError> {
+                    Self::of::<Self>()
+                }
+                fn with_token<R, F: FnOnce(Self)->R>(f: F) -> Result<R, Self::RunError> {
+                    Self::new().map(f)
+                }
+                fn identifier(&self) -> Self::Identifier {
+                    self.0
+                }
+                fn compare(&self, id: &Self::Identifier) -> Result<(), Self::ComparisonError> {
+                    if self.0 == *id {
+                        Ok(())
+                    } else {
+                        Err($crate::macros::TypeMismatch {
+                            name: stringify!($id),
+                            cell: *id,
+                            token: self.0,
+                        })
+                    }
+                }
+            }
+        }
+    }
+};
+($($vis: vis $id: ident),*) => {
+    $($crate::type_token!($vis $id);)*
+}
+}
+#[cfg(feature = "derive")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __derive_token {
+    (runtime $id: ident) => {
+        const _: () = {
+            use $crate::{atomics::AtomicU16, derive::TokenId};
+            #[rustfmt::skip]
+            $crate::__atomic_static!(COUNTER: AtomicU16 = AtomicU16::new(0));
+            impl $crate::core::TokenTrait for $id {
+                type ConstructionError = $crate::macros::PoolExhausted;
+                type RunError = Self::ConstructionError;
+                type Identifier = u16;
+                type ComparisonError = $crate::macros::IdMismatch;
+                type Branded<'a> = Self;
+                fn new() -> Result<Self, Self::ConstructionError> {
+                    // Wrapping around would let two live tokens share an identifier.
+                    COUNTER
+                        .fetch_update(
+                            $crate::atomics::Ordering::Relaxed,
+                            $crate::atomics::Ordering::Relaxed,
+                            |id| id.checked_add(1),
+                        )
+                        .map(|id| $id(unsafe { TokenId::new_unchecked(id) }))
+                        .map_err(|_| $crate::macros::PoolExhausted)
+                }
+                fn with_token<R, F: FnOnce(Self) -> R>(f: F) -> Result<R, Self::RunError> {
+                    Self::new().map(f)
+                }
+                fn identifier(&self) -> Self::Identifier {
+                    *self.0.get()
+                }
+                fn compare(&self, id: &Self::Identifier) -> Result<(), Self::ComparisonError> {
+                    if self.0.get() == id {
+                        Ok(())
+                    } else {
+                        Err($crate::macros::IdMismatch {
+                            name: stringify!($id),
+                            cell: *id,
+                            token: *self.0.get(),
+                        }
+                        .reported())
+                    }
+                }
+            }
+        };
+    };
+    (unsafe $id: ident) => {
+        const _: () = {
+            use core::convert::Infallible;
+            use $crate::derive::TokenId;
+            impl $crate::core::TokenTrait for $id {
+                type ConstructionError = Infallible;
+                type RunError = Infallible;
+                type Identifier = ();
+                type ComparisonError = Infallible;
+                type Branded<'a> = Self;
+                fn new() -> Result<Self, Self::ConstructionError> {
+                    Ok($id(unsafe { TokenId::new_unchecked(()) }))
+                }
+                fn with_token<R, F: FnOnce(Self) -> R>(f: F) -> Result<R, Self::RunError> {
+                    Self::new().map(f)
+                }
+                fn identifier(&self) -> Self::Identifier {
+                    *self.0.get()
+                }
+                fn compare(&self, _: &Self::Identifier) -> Result<(), Self::ComparisonError> {
+                    Ok(())
+                }
+            }
+        };
+    };
+    (singleton $id: ident) => {
+        const _: () = {
+            use core::convert::Infallible;
+            use $crate::{
+                atomics::{AtomicBool, Ordering},
+                derive::TokenId,
+                macros::SingletonUnavailable,
+            };
+            #[rustfmt::skip]
+            $crate::__atomic_static!(AVAILABLE: AtomicBool = AtomicBool::new(true));
+            $crate::__singleton_async!($id);
+            impl $crate::core::TokenTrait for $id {
+                type ConstructionError = SingletonUnavailable;
+                type RunError = SingletonUnavailable;
+                type Identifier = ();
+                type ComparisonError = Infallible;
+                type Branded<'a> = Self;
+                fn new() -> Result<Self, Self::ConstructionError> {
+                    // Acquiring synchronizes with the release store of the previous instance's drop,
+                    // so that its accesses to the cells happen-before those of the new instance.
+                    if AVAILABLE
+                        .compare_exchange(true, false, Ordering::Acquire, Ordering::Relaxed)
+                        .is_ok()
+                    {
+                        Ok($id(unsafe { TokenId::new_unchecked(()) }))
+                    } else {
+                        Err(SingletonUnavailable)
+                    }
+                }
+                fn with_token<R, F: FnOnce(Self) -> R>(f: F) -> Result<R, Self::RunError> {
+                    Self::new().map(f)
+                }
+                fn identifier(&self) -> Self::Identifier {
+                    *self.0.get()
+                }
+                fn compare(&self, _: &Self::Identifier) -> Result<(), Self::ComparisonError> {
+                    Ok(())
+                }
+            }
+            impl ::core::ops::Drop for $id {
+                fn drop(&mut self) {
+                    AVAILABLE.store(true, Ordering::Release);
+                    notify();
+                }
+            }
+        };
+    };
+    (auto $id: ident) => {
+        $crate::__derive_token_auto!($id);
+    };
+}
+#[cfg(all(
+    feature = "derive",
+    any(
+        feature = "force-checked",
+        all(
+            not(feature = "force-unsafe"),
+            any(feature = "debug", debug_assertions)
+        )
+    )
+))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __derive_token_auto {
+    ($id: ident) => {
+        $crate::__derive_token!(runtime $id);
+    };
+}
+#[cfg(all(
+    feature = "derive",
+    not(any(
+        feature = "force-checked",
+        all(
+            not(feature = "force-unsafe"),
+            any(feature = "debug", debug_assertions)
+        )
+    ))
+))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __derive_token_auto {
+    ($id: ident) => {
+        $crate::__derive_token!(unsafe $id);
+    };
+}
+
+/// Constructs a struct whose fields are all [`TokenCell`](crate::core::TokenCell)s keyed by the same token.
+///
+/// The token expression is only evaluated once.
+/// ```rust
+/// # use token_cell::prelude::*;
+/// # token_cell::unsafe_token!(Token);
+/// struct State {
+///     count: TokenCell<u32, Token>,
+///     name: TokenCell<&'static str, Token>,
+/// }
+/// let token = Token::new().unwrap();
+/// let state = token_cell::token_cells!(token => State { count: 0, name: "state" });
+/// assert_eq!(*state.name.borrow(&token), "state");
+/// ```
+#[macro_export]
+macro_rules! token_cells {
+($token: expr => $ty: path { $($field: ident: $value: expr),* $(,)? }) => {
+    {
+        let token = &$token;
+        $ty {
+            $($field: $crate::core::TokenConstruct::new($value, token),)*
+        }
+    }
+};
+}
+
+/// Borrows several cells mutably at once with the same token, yielding a tuple of mutable references.
+///
+/// Unlike [`borrow_many_mut`](crate::core::borrow_many_mut), the cells may have different contents.
+/// The token expression is only evaluated once.
+/// ```rust
+/// # use token_cell::{prelude::*, RuntimeToken};
+/// let mut token = RuntimeToken::new().unwrap();
+/// let state = (TokenCell::new(0, &token), TokenCell::new("a", &token), TokenCell::new(vec![1], &token));
+/// let (count, name, items) = token_cell::borrow_mut!(&mut token; state.0, state.1, state.2).unwrap();
+/// items.push(2);
+/// *count = items.len();
+/// *name = "b";
+/// assert_eq!(*state.0.borrow(&token), 2);
+/// assert!(token_cell::borrow_mut!(&mut RuntimeToken::new().unwrap(); state.0, state.1).is_err());
+/// ```
+///
+/// # Errors
+/// If the token provides runtime checking and detects that any of the cells was constructed with another token.
+///
+/// # Panics
+/// If the contents of any two cells overlap, such as when the same cell is passed twice, after the token has been checked against both.
+/// ```should_panic
+/// # use token_cell::{prelude::*, RuntimeToken};
+/// let mut token = RuntimeToken::new().unwrap();
+/// let cell = TokenCell::new(0, &token);
+/// let _ = token_cell::borrow_mut!(&mut token; cell, cell);
+/// ```
+///
+/// Zero-sized contents at the same address are considered overlapping.
+/// ```should_panic
+/// # use token_cell::{prelude::*, RuntimeToken};
+/// token_cell::unsafe_token!(Inner);
+/// # fn main() {
+/// let mut token = RuntimeToken::new().unwrap();
+/// let holder = TokenCell::new(Inner::new().unwrap(), &token);
+/// let _ = token_cell::borrow_mut!(&mut token; holder, holder);
+/// # }
+/// ```
+#[macro_export]
+macro_rules! borrow_mut {
+($token: expr; $($cell: expr),+ $(,)?) => {
+    match $crate::core::BorrowMany::<_, { [$(stringify!($cell)),+].len() }>::new($token) {
+        mut many => $crate::borrow_mut!(@claim many; []; $($cell),+),
+    }
+};
+(@claim $many: ident; [$($claimed: ident)*]; $cell: expr $(, $rest: expr)*) => {
+    match $many.claim(&$cell) {
+        Ok(claimed) => $crate::borrow_mut!(@claim $many; [$($claimed)* claimed]; $($rest),*),
+        Err(e) => Err(e),
+    }
+};
+(@claim $many: ident; [$($claimed: ident)*];) => {
+    Ok(($($claimed,)*))
+};
+}
+
+/// Aliases [`runtime_token`] with `debug_assertions` or the `debug` feature, and [`unsafe_token`](crate::unsafe_token) otherwise.
+///
+/// The `force-checked` feature keeps tokens runtime-checked regardless of the profile, while `force-unsafe` keeps them unchecked.
+/// If both are enabled, `force-checked` takes precedence.
 pub use token::token;
-#[cfg(any(feature = "debug", debug_assertions))]
+#[cfg(any(
+    feature = "force-checked",
+    all(
+        not(feature = "force-unsafe"),
+        any(feature = "debug", debug_assertions)
+    )
+))]
 mod token {
     pub use crate::runtime_token as token;
 }
-#[cfg(not(any(feature = "debug", debug_assertions)))]
+#[cfg(not(any(
+    feature = "force-checked",
+    all(
+        not(feature = "force-unsafe"),
+        any(feature = "debug", debug_assertions)
+    )
+)))]
 mod token {
+    #[allow(clippy::unsafe_removed_from_name)]
     pub use crate::unsafe_token as token;
 }
 
+/// The flag tracking whether a [`singleton_token`] is available, for tokens declared with `singleton_token!(pub Token in FLAG)`.
+///
+/// The flag can't be accessed directly, as setting it while an instance is alive would allow constructing another one.
+pub struct SingletonFlag(crate::atomics::AtomicBool);
+impl SingletonFlag {
+    /// Constructs a flag for a token that's initially available.
+    #[cfg(not(all(feature = "loom", loom)))]
+    pub const fn new() -> Self {
+        SingletonFlag(crate::atomics::AtomicBool::new(true))
+    }
+    /// Constructs a flag for a token that's initially available.
+    #[cfg(all(feature = "loom", loom))]
+    pub fn new() -> Self {
+        SingletonFlag(crate::atomics::AtomicBool::new(true))
+    }
+    #[doc(hidden)]
+    /// # Safety
+    /// Only the token this flag was declared for may access it.
+    pub const unsafe fn as_atomic(&self) -> &crate::atomics::AtomicBool {
+        &self.0
+    }
+}
+impl Default for SingletonFlag {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Strips `ordering` of its release semantics, making it suitable for a load.
+///
+/// This is the ordering [`singleton_token`]s use to acquire themselves.
+pub const fn acquire_ordering(ordering: Ordering) -> Ordering {
+    match ordering {
+        Ordering::Acquire | Ordering::AcqRel => Ordering::Acquire,
+        Ordering::SeqCst => Ordering::SeqCst,
+        _ => Ordering::Relaxed,
+    }
+}
+
+/// Strips `ordering` of its acquire semantics, making it suitable for a store.
+///
+/// This is the ordering [`singleton_token`]s use to release themselves.
+pub const fn release_ordering(ordering: Ordering) -> Ordering {
+    match ordering {
+        Ordering::Release | Ordering::AcqRel => Ordering::Release,
+        Ordering::SeqCst => Ordering::SeqCst,
+        _ => Ordering::Relaxed,
+    }
+}
+
 /// The comparison error for runtime tokens.
+///
+/// Its [`Display`](core::fmt::Display) implementation names the token type, making mismatches easy to track down in logs.
+/// ```rust
+/// # use token_cell::{prelude::*, RuntimeToken};
+/// let t1 = RuntimeToken::new().unwrap();
+/// let t2 = RuntimeToken::new().unwrap();
+/// let cell = TokenCell::new(0, &t1);
+/// let error = cell.try_borrow(&t2).unwrap_err();
+/// assert_eq!(
+///     error.to_string(),
+///     format!("RuntimeToken: cell expected id {} but token had id {}", error.cell, error.token)
+/// );
+/// ```
 #[derive(Debug, Clone, Copy)]
 pub struct IdMismatch {
+    /// The name of the token type.
+    pub name: &'static str,
     /// The identifier of the token the cell was expecting.
     pub cell: u16,
     /// The identifier of the token that was used to attempt accessing the cell's contents.
     pub token: u16,
 }
+impl IdMismatch {
+    /// Passes the mismatch to the hook set with [`set_mismatch_hook`], if any.
+    #[doc(hidden)]
+    #[cfg(feature = "debug")]
+    pub fn reported(self) -> Self {
+        let hook = MISMATCH_HOOK.load(core::sync::atomic::Ordering::Acquire);
+        if !hook.is_null() {
+            let hook: fn(&IdMismatch) = unsafe { core::mem::transmute(hook) };
+            hook(&self);
+        }
+        self
+    }
+    #[doc(hidden)]
+    #[cfg(not(feature = "debug"))]
+    pub const fn reported(self) -> Self {
+        self
+    }
+}
+#[cfg(feature = "debug")]
+static MISMATCH_HOOK: core::sync::atomic::AtomicPtr<()> =
+    core::sync::atomic::AtomicPtr::new(core::ptr::null_mut());
+/// With the `debug` feature, registers `hook` to be called with every [`IdMismatch`] before it's returned, similarly to [`std::panic::set_hook`].
+///
+/// This lets you aggregate token mix-ups without wrapping every call site. Only the last registered hook is kept.
+/// ```rust
+/// # use token_cell::{prelude::*, RuntimeToken, macros::{set_mismatch_hook, IdMismatch}};
+/// use core::sync::atomic::{AtomicUsize, Ordering};
+/// static MISMATCHES: AtomicUsize = AtomicUsize::new(0);
+/// fn count(_: &IdMismatch) {
+///     MISMATCHES.fetch_add(1, Ordering::Relaxed);
+/// }
+/// set_mismatch_hook(count);
+/// let cell = TokenCell::new(1, &RuntimeToken::new().unwrap());
+/// assert!(cell.try_borrow(&RuntimeToken::new().unwrap()).is_err());
+/// assert_eq!(MISMATCHES.load(Ordering::Relaxed), 1);
+/// ```
+#[cfg(feature = "debug")]
+pub fn set_mismatch_hook(hook: fn(&IdMismatch)) {
+    MISMATCH_HOOK.store(hook as *mut (), core::sync::atomic::Ordering::Release);
+}
 impl ::core::fmt::Display for IdMismatch {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        write!(
+            f,
+            "{}: cell expected id {} but token had id {}",
+            self.name, self.cell, self.token
+        )
+    }
+}
+/// The comparison error for [`type_token`]s.
+#[derive(Debug, Clone, Copy)]
+pub struct TypeMismatch {
+    /// The name of the token type.
+    pub name: &'static str,
+    /// The category the cell was constructed for.
+    pub cell: core::any::TypeId,
+    /// The category of the token that was used to attempt accessing the cell's contents.
+    pub token: core::any::TypeId,
+}
+impl ::core::fmt::Display for TypeMismatch {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        write!(
+            f,
+            "{}: cell expected category {:?} but token had category {:?}",
+            self.name, self.cell, self.token
+        )
+    }
+}
+/// The comparison error for [`arc_runtime_token`]s.
+#[derive(Debug, Clone, Copy)]
+pub struct AddressMismatch {
+    /// The address of the identifier the cell was expecting.
+    pub cell: usize,
+    /// The address of the identifier of the token that was used to attempt accessing the cell's contents.
+    pub token: usize,
+}
+impl ::core::fmt::Display for AddressMismatch {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
+/// The error returned when accessing a cell whose [`revocable_token`] has been dropped.
+#[derive(Debug, Clone, Copy)]
+pub struct TokenExpired;
+impl ::core::fmt::Display for TokenExpired {
     fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
         write!(f, "{:?}", self)
     }
 }
+/// The comparison error for [`revocable_token`]s.
+#[derive(Debug, Clone, Copy)]
+pub enum RevocableMismatch {
+    /// The token the cell was constructed with has been dropped.
+    Expired(TokenExpired),
+    /// The cell was constructed with another token, which is still alive.
+    Mismatch(AddressMismatch),
+}
+impl ::core::fmt::Display for RevocableMismatch {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        match self {
+            RevocableMismatch::Expired(e) => e.fmt(f),
+            RevocableMismatch::Mismatch(e) => e.fmt(f),
+        }
+    }
+}
+/// The construction error for [`runtime_token`]s and [`reclaiming_runtime_token`](crate::reclaiming_runtime_token)s, returned when no identifier is available anymore.
+#[derive(Debug, Clone, Copy)]
+pub struct PoolExhausted;
+impl ::core::fmt::Display for PoolExhausted {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
+/// The comparison error for poisoning [`spin_token`](crate::spin_token)s and [`singleton_token`]s, returned once an instance was dropped during a panic.
+#[derive(Debug, Clone, Copy)]
+pub struct Poisoned;
+impl ::core::fmt::Display for Poisoned {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
+/// The construction error for [`type_token`]s, returned while a token for the requested category is alive.
+#[derive(Debug, Clone, Copy)]
+pub struct CategoryInUse {
+    /// The name of the token type.
+    pub name: &'static str,
+}
+impl ::core::fmt::Display for CategoryInUse {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        write!(
+            f,
+            "{}: a token for this category is already alive",
+            self.name
+        )
+    }
+}
+#[cfg(feature = "std")]
+static CATEGORIES: ::std::sync::Mutex<
+    crate::alloc::vec::Vec<(core::any::TypeId, core::any::TypeId)>,
+> = ::std::sync::Mutex::new(crate::alloc::vec::Vec::new());
+/// Registers `category` as taken for the `token` type, returning `false` if it already was.
+#[cfg(feature = "std")]
+#[doc(hidden)]
+pub fn claim_category(token: core::any::TypeId, category: core::any::TypeId) -> bool {
+    let mut categories = CATEGORIES
+        .lock()
+        .unwrap_or_else(::std::sync::PoisonError::into_inner);
+    if categories.contains(&(token, category)) {
+        false
+    } else {
+        categories.push((token, category));
+        true
+    }
+}
+/// Releases `category` for the `token` type.
+#[cfg(feature = "std")]
+#[doc(hidden)]
+pub fn release_category(token: core::any::TypeId, category: core::any::TypeId) {
+    CATEGORIES
+        .lock()
+        .unwrap_or_else(::std::sync::PoisonError::into_inner)
+        .retain(|&entry| entry != (token, category));
+}
+#[cfg(feature = "std")]
+#[doc(hidden)]
+pub fn panicking() -> bool {
+    extern crate std;
+    std::thread::panicking()
+}
 /// The construction error for [`singleton_token`]s.
 #[derive(Debug, Clone, Copy)]
 pub struct SingletonUnavailable;
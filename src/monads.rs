@@ -1,7 +1,7 @@
 use core::convert::Infallible;
 
 use crate::{
-    core::{TokenGuard, TokenGuardMut},
+    core::{contents, overlap, TokenAccess, TokenGuard, TokenGuardMut},
     prelude::*,
 };
 
@@ -12,7 +12,7 @@ pub struct TokenMap<
     T: ?Sized,
     U,
     F: FnOnce(TokenGuard<'a, T, Token>) -> U,
-    Cell: TokenCellTrait<T, Token> + ?Sized,
+    Cell: TokenAccess<T, Token> + ?Sized,
     Token: TokenTrait + 'a,
 > {
     pub(crate) cell: &'a Cell,
@@ -25,7 +25,7 @@ impl<
         U,
         F: FnOnce(TokenGuard<'a, T, Token>) -> U,
         Token: TokenTrait,
-        Cell: TokenCellTrait<T, Token>,
+        Cell: TokenAccess<T, Token>,
     > TokenMap<'a, T, U, F, Cell, Token>
 {
     /// Attempt to apply the operation.
@@ -38,7 +38,7 @@ impl<
             Err(e) => Err((self, e)),
         }
     }
-    /// Apples the operation.
+    /// Applies the operation.
     pub fn apply(self, token: &'a Token) -> U
     where
         Token: TokenTrait<ComparisonError = Infallible>,
@@ -46,16 +46,236 @@ impl<
         let borrowed = unsafe { self.cell.try_guard(token).unwrap_unchecked() };
         (self.f)(borrowed)
     }
+    /// Applies the operation, panicking if the wrong token was used as key, like [`TokenAccess::borrow`] does.
+    ///
+    /// The panic is reported at the caller's location.
+    /// ```rust
+    /// # use token_cell::{prelude::*, RuntimeToken};
+    /// let token = RuntimeToken::new().unwrap();
+    /// let cell = TokenCell::new(1, &token);
+    /// assert_eq!(cell.map(|value| *value + 1).apply_or_panic(&token), 2);
+    /// ```
+    /// ```should_panic
+    /// # use token_cell::{prelude::*, RuntimeToken};
+    /// let cell = TokenCell::new(1, &RuntimeToken::new().unwrap());
+    /// cell.map(|value| *value + 1).apply_or_panic(&RuntimeToken::new().unwrap());
+    /// ```
+    ///
+    /// # Panics
+    /// If the token comparison failed.
+    #[track_caller]
+    pub fn apply_or_panic(self, token: &'a Token) -> U
+    where
+        Token::ComparisonError: core::fmt::Debug,
+    {
+        (self.f)(self.cell.try_guard(token).unwrap())
+    }
+    /// With the `async` feature, applies the operation once `token` resolves.
+    /// ```rust
+    /// # use token_cell::prelude::*;
+    /// # use std::{future::Future, sync::Arc, task::{Context, Poll, Wake}};
+    /// # struct Noop;
+    /// # impl Wake for Noop { fn wake(self: Arc<Self>) {} }
+    /// # token_cell::unsafe_token!(Token);
+    /// # let waker = Arc::new(Noop).into();
+    /// # let mut cx = Context::from_waker(&waker);
+    /// let token = Token::new().unwrap();
+    /// let cell = TokenCell::new(1, &token);
+    /// let applied = cell.map(|value| *value + 1).apply_async(async { &token });
+    /// assert_eq!(Box::pin(applied).as_mut().poll(&mut cx), Poll::Ready(2));
+    /// ```
+    #[cfg(feature = "async")]
+    pub async fn apply_async<Fut: core::future::Future<Output = &'a Token>>(self, token: Fut) -> U
+    where
+        Token: TokenTrait<ComparisonError = Infallible>,
+    {
+        self.apply(token.await)
+    }
+    /// Combines this operation with an alternate one, which is applied instead if the token doesn't match this operation's cell.
+    ///
+    /// This is only useful with tokens that are checked at runtime, since other tokens always match.
+    /// ```rust
+    /// # use token_cell::{prelude::*, RuntimeToken};
+    /// let (token, other) = (RuntimeToken::new().unwrap(), RuntimeToken::new().unwrap());
+    /// let (a, b) = (TokenCell::new(1, &token), TokenCell::new(2, &other));
+    /// let read = a.map(|a| *a).or_else(b.map(|b| *b));
+    /// assert_eq!(read.try_apply(&other).ok(), Some(2));
+    /// ```
+    pub const fn or_else<
+        T2: ?Sized,
+        F2: FnOnce(TokenGuard<'a, T2, Token>) -> U,
+        Cell2: TokenAccess<T2, Token>,
+    >(
+        self,
+        alternate: TokenMap<'a, T2, U, F2, Cell2, Token>,
+    ) -> FallbackTokenMap<Self, TokenMap<'a, T2, U, F2, Cell2, Token>> {
+        FallbackTokenMap {
+            primary: self,
+            alternate,
+        }
+    }
+    /// Combines this operation with another one, so that both may be applied with a single token.
+    /// ```rust
+    /// # use token_cell::prelude::*;
+    /// # token_cell::unsafe_token!(Token);
+    /// let token = Token::new().unwrap();
+    /// let (a, b) = (TokenCell::new(1, &token), TokenCell::new("two", &token));
+    /// let zipped = a.map(|a| *a + 1).zip(b.map(|b| b.len()));
+    /// assert_eq!(zipped.apply(&token), (2, 3));
+    /// ```
+    pub const fn zip<
+        T2: ?Sized,
+        U2,
+        F2: FnOnce(TokenGuard<'a, T2, Token>) -> U2,
+        Cell2: TokenAccess<T2, Token>,
+    >(
+        self,
+        other: TokenMap<'a, T2, U2, F2, Cell2, Token>,
+    ) -> ZippedTokenMap<Self, TokenMap<'a, T2, U2, F2, Cell2, Token>> {
+        ZippedTokenMap {
+            left: self,
+            right: other,
+        }
+    }
+}
+/// Operations can be cloned if their closure can, e.g. to apply them once per token.
+/// ```rust
+/// # use token_cell::{prelude::*, RuntimeToken};
+/// let tokens = [RuntimeToken::new().unwrap(), RuntimeToken::new().unwrap()];
+/// let cell = TokenCell::new(1, &tokens[1]);
+/// let read = cell.map(|value| *value);
+/// let results: Vec<_> = tokens.iter().map(|token| read.clone().try_apply(token).ok()).collect();
+/// assert_eq!(results, [None, Some(1)]);
+/// ```
+impl<
+        'a,
+        T: ?Sized,
+        U,
+        F: FnOnce(TokenGuard<'a, T, Token>) -> U + Clone,
+        Token: TokenTrait,
+        Cell: TokenAccess<T, Token> + ?Sized,
+    > Clone for TokenMap<'a, T, U, F, Cell, Token>
+{
+    fn clone(&self) -> Self {
+        TokenMap {
+            cell: self.cell,
+            f: self.f.clone(),
+            marker: core::marker::PhantomData,
+        }
+    }
+}
+
+/// An operation waiting to be applied onto a cell, falling back to another one if the token doesn't match the first cell.
+#[must_use = "TokenMaps must be applied to do anything. Note that the closure execution will be deferred to the call-site of `apply/try_apply`"]
+pub struct FallbackTokenMap<P, A> {
+    primary: P,
+    alternate: A,
+}
+impl<
+        'a,
+        T: ?Sized,
+        U,
+        F: FnOnce(TokenGuard<'a, T, Token>) -> U,
+        Cell: TokenAccess<T, Token>,
+        T2: ?Sized,
+        F2: FnOnce(TokenGuard<'a, T2, Token>) -> U,
+        Cell2: TokenAccess<T2, Token>,
+        Token: TokenTrait,
+    > FallbackTokenMap<TokenMap<'a, T, U, F, Cell, Token>, TokenMap<'a, T2, U, F2, Cell2, Token>>
+{
+    /// Attempts to apply the primary operation, applying the alternate one instead if the token doesn't match the primary cell.
+    ///
+    /// Exactly one of the operations is applied on success.
+    ///
+    /// # Errors
+    /// If the token matches neither cell, in which case the alternate cell's error is returned.
+    pub fn try_apply(self, token: &'a Token) -> Result<U, (Self, Token::ComparisonError)> {
+        if let Ok(borrowed) = self.primary.cell.try_guard(token) {
+            return Ok((self.primary.f)(borrowed));
+        }
+        match self.alternate.cell.try_guard(token) {
+            Ok(borrowed) => Ok((self.alternate.f)(borrowed)),
+            Err(e) => Err((self, e)),
+        }
+    }
+}
+
+/// Two operations waiting to be applied onto their cells by providing a single proof of immutable access.
+#[must_use = "TokenMaps must be applied to do anything. Note that the closure execution will be deferred to the call-site of `apply/try_apply`"]
+pub struct ZippedTokenMap<L, R> {
+    left: L,
+    right: R,
+}
+impl<
+        'a,
+        T: ?Sized,
+        U,
+        F: FnOnce(TokenGuard<'a, T, Token>) -> U,
+        Cell: TokenAccess<T, Token>,
+        T2: ?Sized,
+        U2,
+        F2: FnOnce(TokenGuard<'a, T2, Token>) -> U2,
+        Cell2: TokenAccess<T2, Token>,
+        Token: TokenTrait,
+    > ZippedTokenMap<TokenMap<'a, T, U, F, Cell, Token>, TokenMap<'a, T2, U2, F2, Cell2, Token>>
+{
+    /// Attempt to apply both operations.
+    ///
+    /// Neither operation is applied unless the token matches both cells.
+    ///
+    /// # Errors
+    /// If either token comparison failed, in which case the first error encountered is returned. Reaching this error is likely to be a fundamental error in your program.
+    pub fn try_apply(self, token: &'a Token) -> Result<(U, U2), (Self, Token::ComparisonError)> {
+        let left = match self.left.cell.try_guard(token) {
+            Ok(borrowed) => borrowed,
+            Err(e) => return Err((self, e)),
+        };
+        let right = match self.right.cell.try_guard(token) {
+            Ok(borrowed) => borrowed,
+            Err(e) => return Err((self, e)),
+        };
+        Ok(((self.left.f)(left), (self.right.f)(right)))
+    }
+    /// Applies both operations.
+    pub fn apply(self, token: &'a Token) -> (U, U2)
+    where
+        Token: TokenTrait<ComparisonError = Infallible>,
+    {
+        let left = unsafe { self.left.cell.try_guard(token).unwrap_unchecked() };
+        let right = unsafe { self.right.cell.try_guard(token).unwrap_unchecked() };
+        ((self.left.f)(left), (self.right.f)(right))
+    }
 }
 
 /// An operation waiting to be applied onto a cell by providing a proof of mutable access.
+///
+/// Applying it borrows the token for as long as its result may borrow the cell, so operations deferred on the same cell can't alias its contents.
+/// ```rust
+/// # use token_cell::prelude::*;
+/// # token_cell::unsafe_token!(Token);
+/// let mut token = Token::new().unwrap();
+/// let cell = TokenCell::new(1, &token);
+/// let (first, second) = (cell.map_mut(|guard| guard.leak()), cell.map_mut(|guard| guard.leak()));
+/// *first.apply(&mut token) += 1;
+/// *second.apply(&mut token) += 1;
+/// assert_eq!(*cell.borrow(&token), 3);
+/// ```
+/// ```compile_fail
+/// # use token_cell::prelude::*;
+/// # token_cell::unsafe_token!(Token);
+/// let mut token = Token::new().unwrap();
+/// let cell = TokenCell::new(1, &token);
+/// let (first, second) = (cell.map_mut(|guard| guard.leak()), cell.map_mut(|guard| guard.leak()));
+/// let (first, second) = (first.apply(&mut token), second.apply(&mut token));
+/// *first += *second;
+/// ```
 #[must_use = "TokenMaps must be applied to do anything. Note that the closure execution will be deferred to the call-site of `apply/try_apply`"]
 pub struct TokenMapMut<
     'a,
     T: ?Sized,
     U,
     F: FnOnce(TokenGuardMut<'a, T, Token>) -> U,
-    Cell: TokenCellTrait<T, Token> + ?Sized,
+    Cell: TokenAccess<T, Token> + ?Sized,
     Token: TokenTrait + 'a,
 > {
     pub(crate) cell: &'a Cell,
@@ -68,18 +288,20 @@ impl<
         U,
         F: FnOnce(TokenGuardMut<'a, T, Token>) -> U,
         Token: TokenTrait,
-        Cell: TokenCellTrait<T, Token>,
+        Cell: TokenAccess<T, Token>,
     > TokenMapMut<'a, T, U, F, Cell, Token>
 {
     /// Attempt to apply the operation.
     ///
     /// # Errors
     /// If the token comparison failed. Reaching this error is likely to be a fundamental error in your program.
-    pub fn try_apply(self, token: &'a mut Token) -> Result<U, Token::ComparisonError> {
-        let borrowed = self.cell.try_guard_mut(token)?;
-        Ok((self.f)(borrowed))
+    pub fn try_apply(self, token: &'a mut Token) -> Result<U, (Self, Token::ComparisonError)> {
+        match self.cell.try_guard_mut(token) {
+            Ok(borrowed) => Ok((self.f)(borrowed)),
+            Err(e) => Err((self, e)),
+        }
     }
-    /// Apples the operation.
+    /// Applies the operation.
     pub fn apply(self, token: &'a mut Token) -> U
     where
         Token: TokenTrait<ComparisonError = Infallible>,
@@ -87,5 +309,218 @@ impl<
         let borrowed = unsafe { self.cell.try_guard_mut(token).unwrap_unchecked() };
         (self.f)(borrowed)
     }
+    /// Applies the operation, panicking if the wrong token was used as key, like [`TokenAccess::borrow`] does.
+    ///
+    /// The panic is reported at the caller's location.
+    ///
+    /// # Panics
+    /// If the token comparison failed.
+    #[track_caller]
+    pub fn apply_or_panic(self, token: &'a mut Token) -> U
+    where
+        Token::ComparisonError: core::fmt::Debug,
+    {
+        (self.f)(self.cell.try_guard_mut(token).unwrap())
+    }
+}
+impl<
+        'a,
+        T: ?Sized,
+        U,
+        F: FnOnce(TokenGuardMut<'a, T, Token>) -> U + Clone,
+        Token: TokenTrait,
+        Cell: TokenAccess<T, Token> + ?Sized,
+    > Clone for TokenMapMut<'a, T, U, F, Cell, Token>
+{
+    fn clone(&self) -> Self {
+        TokenMapMut {
+            cell: self.cell,
+            f: self.f.clone(),
+            marker: core::marker::PhantomData,
+        }
+    }
 }
 impl<T: ?Sized, Token: TokenTrait> TokenCell<T, Token> {}
+
+/// An operation waiting to be applied onto two cells by providing a single proof of immutable access.
+#[must_use = "TokenMaps must be applied to do anything. Note that the closure execution will be deferred to the call-site of `apply/try_apply`"]
+pub struct TokenMap2<
+    'a,
+    T: ?Sized,
+    T2: ?Sized,
+    U,
+    F: FnOnce(TokenGuard<'a, T, Token>, TokenGuard<'a, T2, Token>) -> U,
+    Cell: TokenAccess<T, Token> + ?Sized,
+    Cell2: TokenAccess<T2, Token> + ?Sized,
+    Token: TokenTrait + 'a,
+> {
+    pub(crate) cell: &'a Cell,
+    pub(crate) other: &'a Cell2,
+    pub(crate) f: F,
+    pub(crate) marker: core::marker::PhantomData<(&'a T, &'a T2, U, Token)>,
+}
+impl<
+        'a,
+        T: ?Sized,
+        T2: ?Sized,
+        U,
+        F: FnOnce(TokenGuard<'a, T, Token>, TokenGuard<'a, T2, Token>) -> U,
+        Cell: TokenAccess<T, Token> + ?Sized,
+        Cell2: TokenAccess<T2, Token> + ?Sized,
+        Token: TokenTrait,
+    > TokenMap2<'a, T, T2, U, F, Cell, Cell2, Token>
+{
+    /// Attempt to apply the operation.
+    ///
+    /// # Errors
+    /// If the token comparison failed for either cell, in which case the first error encountered is returned. Reaching this error is likely to be a fundamental error in your program.
+    pub fn try_apply(self, token: &'a Token) -> Result<U, (Self, Token::ComparisonError)> {
+        let left = match self.cell.try_guard(token) {
+            Ok(borrowed) => borrowed,
+            Err(e) => return Err((self, e)),
+        };
+        let right = match self.other.try_guard(token) {
+            Ok(borrowed) => borrowed,
+            Err(e) => return Err((self, e)),
+        };
+        Ok((self.f)(left, right))
+    }
+    /// Applies the operation.
+    pub fn apply(self, token: &'a Token) -> U
+    where
+        Token: TokenTrait<ComparisonError = Infallible>,
+    {
+        let left = unsafe { self.cell.try_guard(token).unwrap_unchecked() };
+        let right = unsafe { self.other.try_guard(token).unwrap_unchecked() };
+        (self.f)(left, right)
+    }
+}
+
+/// An operation waiting to be applied onto two distinct cells by providing a single proof of mutable access.
+#[must_use = "TokenMaps must be applied to do anything. Note that the closure execution will be deferred to the call-site of `apply/try_apply`"]
+pub struct TokenMapMut2<
+    'a,
+    T: ?Sized,
+    T2: ?Sized,
+    U,
+    F: FnOnce(&'a mut T, &'a mut T2) -> U,
+    Cell: TokenAccess<T, Token> + ?Sized,
+    Cell2: TokenAccess<T2, Token> + ?Sized,
+    Token: TokenTrait + 'a,
+> {
+    pub(crate) cell: &'a Cell,
+    pub(crate) other: &'a Cell2,
+    pub(crate) f: F,
+    pub(crate) marker: core::marker::PhantomData<(&'a T, &'a T2, U, Token)>,
+}
+impl<
+        'a,
+        T: ?Sized,
+        T2: ?Sized,
+        U,
+        F: FnOnce(&'a mut T, &'a mut T2) -> U,
+        Cell: TokenAccess<T, Token> + ?Sized,
+        Cell2: TokenAccess<T2, Token> + ?Sized,
+        Token: TokenTrait,
+    > TokenMapMut2<'a, T, T2, U, F, Cell, Cell2, Token>
+{
+    /// Attempt to apply the operation.
+    ///
+    /// # Errors
+    /// If the token comparison failed for either cell, in which case the first error encountered is returned. Reaching this error is likely to be a fundamental error in your program.
+    ///
+    /// # Panics
+    /// If the contents of both cells overlap, such as when they are the same cell, after the token has been checked against both.
+    /// Zero-sized contents at the same address are considered overlapping.
+    /// ```should_panic
+    /// # use token_cell::{prelude::*, RuntimeToken};
+    /// token_cell::unsafe_token!(Inner);
+    /// # fn main() {
+    /// let mut token = RuntimeToken::new().unwrap();
+    /// let holder = TokenCell::new(Inner::new().unwrap(), &token);
+    /// let _ = holder.map2_mut(&holder, |_, _| ()).try_apply(&mut token);
+    /// # }
+    /// ```
+    pub fn try_apply(self, token: &'a mut Token) -> Result<U, (Self, Token::ComparisonError)> {
+        let left_contents = match self.cell.try_borrow(token) {
+            Ok(borrowed) => contents(borrowed),
+            Err(e) => return Err((self, e)),
+        };
+        let right_contents = match self.other.try_borrow(token) {
+            Ok(borrowed) => contents(borrowed),
+            Err(e) => return Err((self, e)),
+        };
+        assert!(
+            !overlap(left_contents, right_contents),
+            "map2_mut applied to overlapping cells"
+        );
+        let left = match self.cell.try_borrow_mut(token) {
+            Ok(borrowed) => borrowed as *mut T,
+            Err(e) => return Err((self, e)),
+        };
+        let right = match self.other.try_borrow_mut(token) {
+            Ok(borrowed) => borrowed,
+            Err(e) => return Err((self, e)),
+        };
+        Ok((self.f)(unsafe { &mut *left }, right))
+    }
+    /// Applies the operation.
+    ///
+    /// # Panics
+    /// If the contents of both cells overlap, such as when they are the same cell.
+    pub fn apply(self, token: &'a mut Token) -> U
+    where
+        Token: TokenTrait<ComparisonError = Infallible>,
+    {
+        match self.try_apply(token) {
+            Ok(applied) => applied,
+            Err((_, e)) => match e {},
+        }
+    }
+}
+
+/// A mutable guard waiting to be acquired by providing a proof of mutable access.
+/// ```rust
+/// # use token_cell::prelude::*;
+/// # token_cell::unsafe_token!(Token);
+/// let mut token = Token::new().unwrap();
+/// let cell = TokenCell::new(1, &token);
+/// let pending = cell.guard_later();
+/// let mut guard = pending.apply(&mut token);
+/// *guard += 1;
+/// assert_eq!(*guard, 2);
+/// ```
+#[must_use = "PendingGuardMuts must be applied to do anything"]
+pub struct PendingGuardMut<
+    'a,
+    T: ?Sized,
+    Cell: TokenAccess<T, Token> + ?Sized,
+    Token: TokenTrait + 'a,
+> {
+    pub(crate) cell: &'a Cell,
+    pub(crate) marker: core::marker::PhantomData<(&'a T, Token)>,
+}
+impl<'a, T: ?Sized, Token: TokenTrait, Cell: TokenAccess<T, Token> + ?Sized>
+    PendingGuardMut<'a, T, Cell, Token>
+{
+    /// Attempt to acquire the guard.
+    ///
+    /// # Errors
+    /// If the token comparison failed. Reaching this error is likely to be a fundamental error in your program.
+    pub fn try_apply(
+        self,
+        token: &'a mut Token,
+    ) -> Result<TokenGuardMut<'a, T, Token>, (Self, Token::ComparisonError)> {
+        match self.cell.try_guard_mut(token) {
+            Ok(guard) => Ok(guard),
+            Err(e) => Err((self, e)),
+        }
+    }
+    /// Acquires the guard.
+    pub fn apply(self, token: &'a mut Token) -> TokenGuardMut<'a, T, Token>
+    where
+        Token: TokenTrait<ComparisonError = Infallible>,
+    {
+        unsafe { self.cell.try_guard_mut(token).unwrap_unchecked() }
+    }
+}
@@ -0,0 +1,117 @@
+use core::cell::UnsafeCell;
+
+use crate::core::TokenTrait;
+
+/// A cell keyed by two tokens, such as a node's own token and the token of the tree it belongs to.
+///
+/// Either token grants immutable access, while mutable access requires both: holding both mutably is the only way to ensure that no other borrow exists through either of them.
+/// ```rust
+/// # use token_cell::{prelude::*, multi::DualTokenCell, RuntimeToken};
+/// token_cell::runtime_token!(Root);
+/// let (mut root, mut node) = (Root::new().unwrap(), RuntimeToken::new().unwrap());
+/// let cell = DualTokenCell::new(1, &root, &node);
+/// assert_eq!(*cell.try_borrow_first(&root).unwrap(), 1);
+/// assert_eq!(*cell.try_borrow_second(&node).unwrap(), 1);
+/// *cell.try_borrow_mut(&mut root, &mut node).unwrap() += 1;
+/// assert!(cell.try_borrow_second(&RuntimeToken::new().unwrap()).is_err());
+/// ```
+#[repr(C)]
+pub struct DualTokenCell<T: ?Sized, A: TokenTrait, B: TokenTrait> {
+    first_id: A::Identifier,
+    second_id: B::Identifier,
+    inner: UnsafeCell<T>,
+}
+impl<T, A: TokenTrait, B: TokenTrait> DualTokenCell<T, A, B> {
+    /// Constructs a new cell using both `first` and `second` as keys.
+    pub fn new(inner: T, first: &A, second: &B) -> Self {
+        DualTokenCell {
+            first_id: first.identifier(),
+            second_id: second.identifier(),
+            inner: UnsafeCell::new(inner),
+        }
+    }
+    /// Unwraps the value from the cell.
+    ///
+    /// Full ownership of the cell is sufficient proof that the inner value can be recovered.
+    pub fn into_inner(self) -> T {
+        self.inner.into_inner()
+    }
+}
+impl<T: ?Sized, A: TokenTrait, B: TokenTrait> DualTokenCell<T, A, B> {
+    /// While cells are typically behind immutable references,
+    /// obtaining a mutable reference to one is still proof of unique access.
+    pub const fn get_mut(&mut self) -> &mut T {
+        self.inner.get_mut()
+    }
+    /// Borrows the inner data immutably using the first token.
+    ///
+    /// # Errors
+    /// If the token provides runtime checking and detects that `self` was constructed with another token.
+    pub fn try_borrow_first<'l>(&'l self, first: &'l A) -> Result<&'l T, A::ComparisonError> {
+        first
+            .compare(&self.first_id)
+            .map(|()| unsafe { &*self.inner.get() })
+    }
+    /// Borrows the inner data immutably using the second token.
+    ///
+    /// # Errors
+    /// If the token provides runtime checking and detects that `self` was constructed with another token.
+    pub fn try_borrow_second<'l>(&'l self, second: &'l B) -> Result<&'l T, B::ComparisonError> {
+        second
+            .compare(&self.second_id)
+            .map(|()| unsafe { &*self.inner.get() })
+    }
+    /// Borrows the inner data mutably, which requires both tokens.
+    ///
+    /// # Errors
+    /// If either token provides runtime checking and detects that `self` was constructed with another token.
+    pub fn try_borrow_mut<'l>(
+        &'l self,
+        first: &'l mut A,
+        second: &'l mut B,
+    ) -> Result<&'l mut T, DualMismatch<A, B>> {
+        first.compare(&self.first_id).map_err(DualMismatch::First)?;
+        second
+            .compare(&self.second_id)
+            .map_err(DualMismatch::Second)?;
+        Ok(unsafe { &mut *self.inner.get() })
+    }
+}
+unsafe impl<T: ?Sized + Send + Sync, A: TokenTrait, B: TokenTrait> Sync for DualTokenCell<T, A, B>
+where
+    A::Identifier: Sync,
+    B::Identifier: Sync,
+{
+}
+
+/// The error returned when either token doesn't match a [`DualTokenCell`].
+pub enum DualMismatch<A: TokenTrait, B: TokenTrait> {
+    /// The first token didn't match.
+    First(A::ComparisonError),
+    /// The second token didn't match.
+    Second(B::ComparisonError),
+}
+impl<A: TokenTrait, B: TokenTrait> core::fmt::Debug for DualMismatch<A, B>
+where
+    A::ComparisonError: core::fmt::Debug,
+    B::ComparisonError: core::fmt::Debug,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            DualMismatch::First(e) => f.debug_tuple("First").field(e).finish(),
+            DualMismatch::Second(e) => f.debug_tuple("Second").field(e).finish(),
+        }
+    }
+}
+impl<A: TokenTrait, B: TokenTrait> core::fmt::Display for DualMismatch<A, B>
+where
+    A::ComparisonError: core::fmt::Display,
+    B::ComparisonError: core::fmt::Display,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            DualMismatch::First(e) => e.fmt(f),
+            DualMismatch::Second(e) => e.fmt(f),
+        }
+    }
+}
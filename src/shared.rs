@@ -0,0 +1,100 @@
+use core::ops::{Deref, DerefMut};
+use std::sync::{Arc, Mutex, MutexGuard, PoisonError};
+
+use crate::core::{TokenAccess, TokenCell, TokenGuardMut, TokenTrait};
+
+#[doc(hidden)]
+pub use std::sync::OnceLock;
+
+/// A cell shared between threads, along with the key that unlocks it: a token behind a [`Mutex`].
+///
+/// Cells constructed with [`SharedCell::share`] share the key, so locking any of them excludes all the others.
+/// Tokens produced by [`mutex_token`](crate::mutex_token) come with such a key, so their cells can be constructed with `share` directly.
+/// Unlike [`Mutex`], poisoning is ignored: a panic while a [`SharedGuard`] is held can leave `T` half-updated, and later calls to [`SharedCell::lock`] won't report it.
+/// ```rust
+/// # use token_cell::{prelude::*, shared::SharedCell, RuntimeToken};
+/// let counter = SharedCell::new(0, RuntimeToken::new().unwrap());
+/// let total = counter.share(0);
+/// let handles: Vec<_> = (0..4)
+///     .map(|_| {
+///         let (counter, total) = (counter.clone(), total.clone());
+///         std::thread::spawn(move || {
+///             *counter.lock().unwrap() += 1;
+///             *total.lock().unwrap() += 10;
+///         })
+///     })
+///     .collect();
+/// for handle in handles {
+///     handle.join().unwrap();
+/// }
+/// assert_eq!(*counter.lock().unwrap(), 4);
+/// assert_eq!(*total.lock().unwrap(), 40);
+/// ```
+pub struct SharedCell<T: ?Sized, Token: TokenTrait> {
+    key: Arc<Mutex<Token>>,
+    cell: Arc<TokenCell<T, Token>>,
+}
+impl<T, Token: TokenTrait> SharedCell<T, Token> {
+    /// Constructs a cell keyed by `token`, which becomes the cell's key.
+    pub fn new(inner: T, token: Token) -> Self {
+        Self::with_key(inner, Arc::new(Mutex::new(token)))
+    }
+    /// Constructs a cell unlocked by an existing `key`, such as the one a [`mutex_token`](crate::mutex_token) keeps its only instance in.
+    pub fn with_key(inner: T, key: Arc<Mutex<Token>>) -> Self {
+        let token = key.lock().unwrap_or_else(PoisonError::into_inner);
+        let cell = Arc::new(TokenCell::new(inner, &*token));
+        drop(token);
+        SharedCell { key, cell }
+    }
+}
+impl<T: ?Sized, Token: TokenTrait> SharedCell<T, Token> {
+    /// Constructs another cell unlocked by the same key.
+    pub fn share<U>(&self, inner: U) -> SharedCell<U, Token> {
+        SharedCell::with_key(inner, self.key.clone())
+    }
+    /// Blocks until the key is available, then borrows the contents mutably.
+    ///
+    /// This succeeds even if another thread panicked while holding the key, in which case the contents may be left half-updated.
+    ///
+    /// # Errors
+    /// If the token provides runtime checking and detects that the cell was constructed with another token, which can't happen unless the key was replaced.
+    pub fn lock(&self) -> Result<SharedGuard<'_, T, Token>, Token::ComparisonError> {
+        let token = self.key.lock().unwrap_or_else(PoisonError::into_inner);
+        self.cell.try_borrow(&token)?;
+        Ok(SharedGuard {
+            token,
+            cell: &self.cell,
+        })
+    }
+}
+impl<T: ?Sized, Token: TokenTrait> Clone for SharedCell<T, Token> {
+    fn clone(&self) -> Self {
+        SharedCell {
+            key: self.key.clone(),
+            cell: self.cell.clone(),
+        }
+    }
+}
+
+/// A locked [`SharedCell`], which [`DerefMut`]s to its contents.
+pub struct SharedGuard<'a, T: ?Sized, Token: TokenTrait> {
+    token: MutexGuard<'a, Token>,
+    cell: &'a TokenCell<T, Token>,
+}
+impl<'a, T: ?Sized, Token: TokenTrait> SharedGuard<'a, T, Token> {
+    /// Borrows the locked cell through a [`TokenGuardMut`], which gives access to the key's token.
+    pub fn guard(&mut self) -> TokenGuardMut<'_, T, Token> {
+        unsafe { TokenGuardMut::from_parts(self.cell, &mut self.token) }
+    }
+}
+impl<'a, T: ?Sized, Token: TokenTrait> Deref for SharedGuard<'a, T, Token> {
+    type Target = T;
+    fn deref(&self) -> &Self::Target {
+        unsafe { &*self.cell.get_mut_unchecked() }
+    }
+}
+impl<'a, T: ?Sized, Token: TokenTrait> DerefMut for SharedGuard<'a, T, Token> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        unsafe { self.cell.get_mut_unchecked() }
+    }
+}
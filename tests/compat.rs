@@ -0,0 +1,18 @@
+//! Code written against `TokenCellTrait` alone, before it was split into `TokenAccess` and `TokenConstruct`.
+use token_cell::core::{TokenCell, TokenCellTrait, TokenTrait};
+
+token_cell::unsafe_token!(Token);
+
+fn increment<Cell: TokenCellTrait<u32, Token>>(cell: &Cell, token: &mut Token) {
+    *cell.borrow_mut(token) += 1;
+}
+
+#[test]
+fn token_cell_trait_import_is_enough() {
+    let mut token = Token::new().unwrap();
+    let cell = TokenCell::new(1, &token);
+    increment(&cell, &mut token);
+    assert_eq!(*cell.borrow(&token), 2);
+    assert_eq!(*cell.try_borrow(&token).unwrap(), 2);
+    assert_eq!(cell.map(|value| *value + 1).apply(&token), 3);
+}
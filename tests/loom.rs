@@ -0,0 +1,58 @@
+#![cfg(all(feature = "loom", loom))]
+use loom::{
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc,
+    },
+    thread,
+};
+use token_cell::prelude::*;
+
+token_cell::singleton_token!(Key; ordering = AcqRel);
+token_cell::spin_token!(Lock);
+
+#[test]
+fn singleton_excludes_across_threads() {
+    loom::model(|| {
+        let token = Key::new().unwrap();
+        let holders = Arc::new(TokenCell::new(AtomicUsize::new(0), &token));
+        drop(token);
+        let handles: Vec<_> = (0..2)
+            .map(|_| {
+                let holders = holders.clone();
+                thread::spawn(move || {
+                    if let Ok(token) = Key::new() {
+                        let holders = holders.borrow(&token);
+                        assert_eq!(holders.fetch_add(1, Ordering::Relaxed), 0);
+                        holders.fetch_sub(1, Ordering::Relaxed);
+                    }
+                })
+            })
+            .collect();
+        for handle in handles {
+            handle.join().unwrap();
+        }
+        assert!(Key::new().is_ok());
+    });
+}
+
+#[test]
+fn spin_token_serializes_threads() {
+    loom::model(|| {
+        let counter = Arc::new(TokenCell::new(0, &Lock::new().unwrap()));
+        let handles: Vec<_> = (0..2)
+            .map(|_| {
+                let counter = counter.clone();
+                thread::spawn(move || {
+                    let mut lock = Lock::new().unwrap();
+                    let counter = counter.borrow_mut(&mut lock);
+                    *counter += 1;
+                })
+            })
+            .collect();
+        for handle in handles {
+            handle.join().unwrap();
+        }
+        assert_eq!(*counter.borrow(&Lock::new().unwrap()), 2);
+    });
+}
@@ -0,0 +1,50 @@
+use token_cell::{macros::IdMismatch, prelude::*};
+
+token_cell::runtime_token!(Key);
+token_cell::reclaiming_runtime_token!(Reclaimed[4]);
+
+#[test]
+fn mismatched_token_reports_both_ids() {
+    let (owner, other) = (Key::new().unwrap(), Key::new().unwrap());
+    let cell = TokenCell::new(1, &owner);
+    let IdMismatch {
+        name,
+        cell: id,
+        token,
+    } = cell.try_borrow(&other).unwrap_err();
+    assert_eq!(name, "Key");
+    assert_eq!(id, owner.identifier());
+    assert_eq!(token, other.identifier());
+    assert_ne!(id, token);
+}
+
+#[test]
+fn matching_token_is_accepted_repeatedly() {
+    let mut token = Key::new().unwrap();
+    let cell = TokenCell::new(1, &token);
+    assert_eq!(*cell.try_borrow(&token).unwrap(), 1);
+    *cell.try_borrow_mut(&mut token).unwrap() += 1;
+    assert_eq!(*cell.try_borrow(&token).unwrap(), 2);
+}
+
+#[test]
+fn identifiers_are_distinct() {
+    let tokens: Vec<_> = (0..64).map(|_| Key::new().unwrap()).collect();
+    let cells: Vec<_> = tokens
+        .iter()
+        .map(|token| TokenCell::new((), token))
+        .collect();
+    for (i, cell) in cells.iter().enumerate() {
+        for (j, token) in tokens.iter().enumerate() {
+            assert_eq!(cell.try_borrow(token).is_ok(), i == j);
+        }
+    }
+}
+
+#[test]
+fn reclaimed_identifiers_are_distinct_while_alive() {
+    let (a, b) = (Reclaimed::new().unwrap(), Reclaimed::new().unwrap());
+    let cell = TokenCell::new(1, &a);
+    let error = cell.try_borrow(&b).unwrap_err();
+    assert_eq!((error.cell, error.token), (a.identifier(), b.identifier()));
+}
@@ -0,0 +1,66 @@
+#![deny(missing_docs)]
+
+//! Derive macros for [`token-cell`](https://crates.io/crates/token-cell), re-exported by its `derive` feature.
+use proc_macro::TokenStream;
+use quote::quote;
+use syn::{parse_macro_input, spanned::Spanned, Data, DeriveInput, Error, Fields, Ident};
+
+/// Implements `TokenTrait` for a tuple struct whose only field is a `token_cell::derive::TokenId`.
+///
+/// See `token_cell::derive` for the available `#[token(...)]` modes.
+#[proc_macro_derive(Token, attributes(token))]
+pub fn derive_token(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand(input)
+        .unwrap_or_else(Error::into_compile_error)
+        .into()
+}
+
+fn expand(input: DeriveInput) -> syn::Result<proc_macro2::TokenStream> {
+    if !input.generics.params.is_empty() {
+        return Err(Error::new(input.generics.span(), "tokens can't be generic"));
+    }
+    match &input.data {
+        Data::Struct(data) => match &data.fields {
+            Fields::Unnamed(fields) if fields.unnamed.len() == 1 => {}
+            fields => {
+                return Err(Error::new(
+                    fields.span(),
+                    "tokens must have a single unnamed `token_cell::derive::TokenId` field",
+                ))
+            }
+        },
+        _ => {
+            return Err(Error::new(
+                input.ident.span(),
+                "tokens must be tuple structs",
+            ))
+        }
+    }
+    let mut mode = None;
+    for attr in input
+        .attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident("token"))
+    {
+        attr.parse_nested_meta(|meta| {
+            let ident = meta
+                .path
+                .get_ident()
+                .filter(|ident| {
+                    ["runtime", "singleton", "unsafe", "auto"].contains(&&*ident.to_string())
+                })
+                .ok_or_else(|| {
+                    meta.error("expected one of `runtime`, `singleton`, `unsafe` or `auto`")
+                })?;
+            if mode.is_some() {
+                return Err(meta.error("only one token mode may be selected"));
+            }
+            mode = Some(ident.clone());
+            Ok(())
+        })?;
+    }
+    let mode = mode.unwrap_or_else(|| Ident::new("runtime", input.ident.span()));
+    let id = &input.ident;
+    Ok(quote!(::token_cell::__derive_token!(#mode #id);))
+}